@@ -1,3 +1,6 @@
+use std::fmt;
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize)]
@@ -13,8 +16,106 @@ pub struct PVPConfig {
     pub knockback: bool,
     /// Whether players swing when attacking.
     pub swing: bool,
-    /// The type of combat mechanics that are used by default. Options: "Legacy" (MC 1.7.10), "Classic" (MC 1.8), "Modern" (Current)
-    pub combat_type: String,
+    /// The type of combat mechanics that are used by default.
+    pub combat_type: CombatType,
+    /// Knockback/friction tuning for the Legacy (MC 1.7.10) combat type.
+    pub legacy: CombatTuning,
+    /// Knockback/friction tuning for the Classic (MC 1.8) combat type.
+    pub classic: CombatTuning,
+    /// Knockback/friction tuning for the Modern (current) combat type.
+    pub modern: CombatTuning,
+}
+
+impl PVPConfig {
+    /// Returns the knockback/friction tuning for the configured combat type.
+    #[must_use]
+    pub fn tuning(&self) -> &CombatTuning {
+        match self.combat_type {
+            CombatType::Legacy => &self.legacy,
+            CombatType::Classic => &self.classic,
+            CombatType::Modern => &self.modern,
+        }
+    }
+}
+
+impl Default for PVPConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            hurt_animation: true,
+            protect_creative: true,
+            knockback: true,
+            swing: true,
+            combat_type: CombatType::default(),
+            legacy: CombatTuning::default(),
+            classic: CombatTuning::default(),
+            modern: CombatTuning::default(),
+        }
+    }
+}
+
+/// The combat mechanics a server uses. Each variant maps to a Minecraft era and
+/// carries its own knockback/friction tuning via [`PVPConfig`].
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(try_from = "String")]
+pub enum CombatType {
+    /// MC 1.7.10 combat.
+    Legacy,
+    /// MC 1.8 combat.
+    Classic,
+    /// Current combat.
+    #[default]
+    Modern,
+}
+
+/// Error returned when a `combat_type` string cannot be resolved to a
+/// [`CombatType`]. Lists the accepted names so a typo is a load-time error with
+/// an actionable message rather than undefined gameplay behavior.
+#[derive(Debug)]
+pub struct CombatTypeParseError {
+    input: String,
+}
+
+impl fmt::Display for CombatTypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown combat type '{}', expected one of: Legacy (\"1.7.10\"), Classic (\"1.8\"), Modern (\"current\"/\"modern\")",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for CombatTypeParseError {}
+
+impl FromStr for CombatType {
+    type Err = CombatTypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "legacy" | "1.7.10" => Ok(Self::Legacy),
+            "classic" | "1.8" => Ok(Self::Classic),
+            "modern" | "current" => Ok(Self::Modern),
+            _ => Err(CombatTypeParseError {
+                input: s.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for CombatType {
+    type Error = CombatTypeParseError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+/// The numeric knockback and friction knobs shared by the combat profiles. Each
+/// combat type owns an instance so its defaults can be tuned independently.
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(default)]
+pub struct CombatTuning {
     /// 2.0 by default.
     pub friction: f64,
     /// 0.4 by default.
@@ -29,15 +130,9 @@ pub struct PVPConfig {
     pub extra_vertical_kb: f64,
 }
 
-impl Default for PVPConfig {
+impl Default for CombatTuning {
     fn default() -> Self {
         Self {
-            enabled: true,
-            hurt_animation: true,
-            protect_creative: true,
-            knockback: true,
-            swing: true,
-            combat_type: String::from("Modern"),
             friction: 2.0,
             horizontal_kb: 0.4,
             vertical_kb: 0.4,
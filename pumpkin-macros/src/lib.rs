@@ -162,13 +162,70 @@ pub fn packet(input: TokenStream, item: TokenStream) -> TokenStream {
     let name = &ast.ident;
     let (impl_generics, ty_generics, _) = ast.generics.split_for_impl();
 
-    let input: proc_macro2::TokenStream = input.into();
+    let input_string = input.to_string();
     let item: proc_macro2::TokenStream = item.into();
 
+    // The attribute is `#[packet(<id>)]` or `#[packet(<id>, versions = "<lo>..=<hi>")]`.
+    // The version predicate gates which negotiated protocol numbers this packet
+    // exists for, so a single codebase can serve adjacent protocol versions.
+    let mut parts = input_string.splitn(2, ',');
+    let id: proc_macro2::TokenStream = parts
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .parse()
+        .expect("packet id must be a valid expression");
+
+    // The inclusive protocol range this packet exists for, recorded as a
+    // per-type `Some((lo, hi))` registry entry. `None` means "every protocol".
+    let version_range = parts
+        .next()
+        .and_then(|rest| rest.trim().strip_prefix("versions"))
+        .and_then(|rest| rest.trim().strip_prefix('='))
+        .and_then(|rest| {
+            let range = rest.trim().trim_matches('"');
+            let (lo, hi) = range.split_once("..=")?;
+            let lo: i32 = lo.trim().parse().ok()?;
+            let hi: i32 = hi.trim().parse().ok()?;
+            Some(quote! { Some((#lo, #hi)) })
+        })
+        .unwrap_or_else(|| quote! { None });
+
     let code = quote! {
         #item
         impl #impl_generics crate::packet::Packet for #name #ty_generics {
-            const PACKET_ID: i32 = #input;
+            // `PACKET_ID` is the protocol-independent wire id and is deliberately
+            // a flat constant: a type either exists in the build or it does not,
+            // so there is no version at which the constant itself is absent.
+            // Whether the packet is *valid* for a negotiated protocol is a
+            // runtime property, exposed through `supports_protocol` /
+            // `packet_id_for` below rather than by hiding the constant.
+            const PACKET_ID: i32 = #id;
+        }
+        impl #impl_generics #name #ty_generics {
+            /// The inclusive `(min, max)` protocol range this packet exists for,
+            /// or `None` when it is valid for every protocol version.
+            pub const PROTOCOL_VERSIONS: Option<(i32, i32)> = #version_range;
+
+            /// Returns whether this packet exists for the negotiated `protocol`.
+            #[must_use]
+            pub fn supports_protocol(protocol: i32) -> bool {
+                match Self::PROTOCOL_VERSIONS {
+                    Some((lo, hi)) => (lo..=hi).contains(&protocol),
+                    None => true,
+                }
+            }
+
+            /// Returns this packet's id for the negotiated `protocol` number, or
+            /// `None` when the packet does not exist for that protocol version.
+            #[must_use]
+            pub fn packet_id_for(protocol: i32) -> Option<i32> {
+                if Self::supports_protocol(protocol) {
+                    Some(#id)
+                } else {
+                    None
+                }
+            }
         }
     };
 
@@ -205,6 +262,91 @@ pub fn pumpkin_block(input: TokenStream, item: TokenStream) -> TokenStream {
     code.into()
 }
 
+/// Centralizes how a primitive block-property value is stringified and parsed
+/// back from its canonical text form. Keeping every scalar kind behind one enum
+/// means adding support for another primitive is a new variant here rather than
+/// another bespoke branch in [`block_property`].
+enum Conversion {
+    Int,
+    Float,
+    Bool,
+    Bytes,
+}
+
+impl Conversion {
+    /// Picks the conversion strategy for a primitive type identifier.
+    fn for_type(ty: &proc_macro2::Ident) -> Self {
+        match ty.to_string().as_str() {
+            "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize" => {
+                Self::Int
+            }
+            "f32" | "f64" => Self::Float,
+            "bool" => Self::Bool,
+            _ => Self::Bytes,
+        }
+    }
+
+    /// Tokens that turn the stored value `inner` into its canonical `String`.
+    fn to_value(&self, inner: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Int | Self::Float | Self::Bool => quote! { (#inner).to_string() },
+            Self::Bytes => quote! { String::from_utf8_lossy(&#inner).into_owned() },
+        }
+    }
+
+    /// Tokens that parse `text` back into the stored value, yielding an `Option`.
+    fn from_value(&self, text: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Int | Self::Float | Self::Bool => quote! { (#text).parse().ok() },
+            Self::Bytes => quote! { Some(#text.as_bytes().to_vec().into_boxed_slice()) },
+        }
+    }
+}
+
+/// Parses an inclusive integer range from the attribute input for an integer
+/// newtype property, e.g. `"age", 0..=15` on a `struct Age(u8)`.
+///
+/// Returns the field type together with the range bounds, or `None` when the
+/// attribute carries no range or the annotated item is not a single-field
+/// integer newtype.
+fn int_property_range(
+    ast: &DeriveInput,
+    input_string: &str,
+) -> Option<(proc_macro2::Ident, proc_macro2::Literal, proc_macro2::Literal)> {
+    let (start, end) = input_string.split_once("..=")?;
+    let start = start.rsplit(',').next()?.trim();
+    let end = end.trim().trim_end_matches([')', ']']).trim();
+
+    let syn::Data::Struct(s) = &ast.data else {
+        return None;
+    };
+    let Fields::Unnamed(fields) = &s.fields else {
+        return None;
+    };
+    if fields.unnamed.len() != 1 {
+        return None;
+    }
+    let field = fields.unnamed.first()?;
+    let syn::Type::Path(type_path) = &field.ty else {
+        return None;
+    };
+    let ty = type_path.path.segments.first()?.ident.clone();
+    if !matches!(
+        ty.to_string().as_str(),
+        "u8" | "u16" | "u32" | "u64" | "usize" | "i8" | "i16" | "i32" | "i64" | "isize"
+    ) {
+        return None;
+    }
+
+    let start: i128 = start.parse().ok()?;
+    let end: i128 = end.parse().ok()?;
+    Some((
+        ty,
+        proc_macro2::Literal::i128_unsuffixed(start),
+        proc_macro2::Literal::i128_unsuffixed(end),
+    ))
+}
+
 #[proc_macro_error]
 #[proc_macro_attribute]
 pub fn block_property(input: TokenStream, item: TokenStream) -> TokenStream {
@@ -214,7 +356,12 @@ pub fn block_property(input: TokenStream, item: TokenStream) -> TokenStream {
 
     let input_string = input.to_string();
     let input_parts: Vec<&str> = input_string.split("[").collect();
-    let property_name = input_parts[0].trim_ascii().trim_matches(&['"', ','][..]);
+    let property_name = input_parts[0]
+        .split(',')
+        .next()
+        .unwrap_or(input_parts[0])
+        .trim_ascii()
+        .trim_matches(&['"', ','][..]);
     let mut property_values: Vec<&str> = Vec::new();
     if input_parts.len() > 1 {
         property_values = input_parts[1]
@@ -226,6 +373,73 @@ pub fn block_property(input: TokenStream, item: TokenStream) -> TokenStream {
 
     let item: proc_macro2::TokenStream = item.into();
 
+    // Integer-backed properties carry an inclusive range in the attribute, e.g.
+    // `#[block_property("age", 0..=15)]`. These are handled separately from the
+    // enum/bool path because the set of legal values is a contiguous numeric
+    // range rather than a fixed list of named variants.
+    if let Some((field_ty, start, end)) = int_property_range(&ast, &input_string) {
+        let conversion = Conversion::for_type(&field_ty);
+        let value_expr = conversion.to_value(quote! { self.0 });
+        let parse_expr = conversion.from_value(quote! { value.as_str() });
+
+        let code = quote! {
+            #item
+            impl #name {
+                /// The smallest legal value for this property.
+                pub const MIN: #field_ty = #start;
+                /// The largest legal value for this property.
+                pub const MAX: #field_ty = #end;
+
+                /// Constructs the property, panicking if `value` lies outside `MIN..=MAX`.
+                pub fn new(value: #field_ty) -> Self {
+                    match Self::try_new(value) {
+                        Some(property) => property,
+                        None => panic!(
+                            "value {} out of range {}..={} for block property `{}`",
+                            value, Self::MIN, Self::MAX, #property_name,
+                        ),
+                    }
+                }
+
+                /// Constructs the property, returning `None` if `value` is out of range.
+                #[must_use]
+                pub fn try_new(value: #field_ty) -> Option<Self> {
+                    if (Self::MIN..=Self::MAX).contains(&value) {
+                        Some(Self(value))
+                    } else {
+                        None
+                    }
+                }
+            }
+            impl #impl_generics pumpkin_world::block::properties::BlockPropertyMetadata for #name #ty_generics {
+                fn name(&self) -> &'static str {
+                    #property_name
+                }
+                fn value(&self) -> String {
+                    #value_expr
+                }
+                fn from_value(value: String) -> Self {
+                    let parsed: #field_ty = match #parse_expr {
+                        Some(parsed) => parsed,
+                        None => panic!(
+                            "`{}` is not a valid value for block property `{}`",
+                            value, #property_name,
+                        ),
+                    };
+                    match Self::try_new(parsed) {
+                        Some(property) => property,
+                        None => panic!(
+                            "value {} out of range {}..={} for block property `{}`",
+                            parsed, Self::MIN, Self::MAX, #property_name,
+                        ),
+                    }
+                }
+            }
+        };
+
+        return code.into();
+    }
+
     let (variants, is_enum): (Vec<proc_macro2::Ident>, bool) = match ast.data {
         syn::Data::Enum(enum_item) => (
             enum_item.variants.into_iter().map(|v| v.ident).collect(),
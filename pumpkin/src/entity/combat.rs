@@ -5,6 +5,7 @@ use crate::{
 };
 use dashmap::DashMap;
 use pumpkin_config::advanced_config;
+use pumpkin_config::pvp::CombatType as ConfigCombatType;
 
 use pumpkin_data::{
     particle::Particle,
@@ -151,41 +152,30 @@ pub static COMBAT_PROFILES: LazyLock<DashMap<Uuid, Arc<dyn CombatProfile + Send
 pub static GLOBAL_COMBAT_PROFILE: LazyLock<Arc<dyn CombatProfile + Send + Sync>> = LazyLock::new(
     || {
         let config = &advanced_config().pvp;
+        let tuning = config.tuning();
 
-        match config.combat_type.to_lowercase().as_str() {
-            "classic" => {
+        match config.combat_type {
+            // Legacy (1.7.10) reuses the classic knockback maths with its own tuning.
+            ConfigCombatType::Legacy | ConfigCombatType::Classic => {
                 log::info!("Loaded Classic Combat Profile");
                 Arc::new(ClassicProfile {
-                    friction: config.friction,
-                    horizontal_kb: config.horizontal_kb,
-                    vertical_kb: config.vertical_kb,
-                    vertical_limit: config.vertical_limit,
-                    extra_horizontal_kb: config.extra_horizontal_kb,
-                    extra_vertical_kb: config.extra_vertical_kb,
+                    friction: tuning.friction,
+                    horizontal_kb: tuning.horizontal_kb,
+                    vertical_kb: tuning.vertical_kb,
+                    vertical_limit: tuning.vertical_limit,
+                    extra_horizontal_kb: tuning.extra_horizontal_kb,
+                    extra_vertical_kb: tuning.extra_vertical_kb,
                 })
             }
-            "modern" => {
+            ConfigCombatType::Modern => {
                 log::info!("Loaded Modern Combat Profile");
                 Arc::new(ModernProfile {
-                    friction: config.friction,
-                    horizontal_kb: config.horizontal_kb,
-                    vertical_kb: config.vertical_kb,
-                    vertical_limit: config.vertical_limit,
-                    extra_horizontal_kb: config.extra_horizontal_kb,
-                    extra_vertical_kb: config.extra_vertical_kb,
-                })
-            }
-            unknown => {
-                log::warn!(
-                    "Combat Profile '{unknown}' does not exist! Falling back to Modern Combat Profile instead."
-                );
-                Arc::new(ModernProfile {
-                    friction: config.friction,
-                    horizontal_kb: config.horizontal_kb,
-                    vertical_kb: config.vertical_kb,
-                    vertical_limit: config.vertical_limit,
-                    extra_horizontal_kb: config.extra_horizontal_kb,
-                    extra_vertical_kb: config.extra_vertical_kb,
+                    friction: tuning.friction,
+                    horizontal_kb: tuning.horizontal_kb,
+                    vertical_kb: tuning.vertical_kb,
+                    vertical_limit: tuning.vertical_limit,
+                    extra_horizontal_kb: tuning.extra_horizontal_kb,
+                    extra_vertical_kb: tuning.extra_vertical_kb,
                 })
             }
         }
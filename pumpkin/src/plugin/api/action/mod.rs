@@ -0,0 +1,243 @@
+//! A generic, per-entity command/action queue so that NPCs and scripted mobs
+//! can enqueue the same high-level actions as player input — place a block, open
+//! or close a container, climb, enter a bed, follow a target.
+//!
+//! Dispatch fires the matching player event only when the actor is a player and
+//! the action is a block placement or a bed enter/leave; actions of non-player
+//! actors and the other action kinds fire no event.
+//!
+//! The queue processes one action per tick and supports cancellation and
+//! interruption, mirroring how player input is serviced a tick at a time.
+
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use pumpkin_data::Block;
+
+use crate::entity::{EntityBase, player::Player};
+
+/// The actor an event or action is performed on behalf of.
+///
+/// Player input and NPC scripting both resolve to an [`EntityBase`], so event
+/// handlers and action validation can treat them uniformly instead of
+/// hard-coding `Arc<Player>`.
+#[derive(Clone)]
+pub enum Actor {
+    /// A real, connected player.
+    Player(Arc<Player>),
+    /// Any other entity (NPC, scripted mob).
+    Entity(Arc<dyn EntityBase>),
+}
+
+impl Actor {
+    /// Returns the underlying entity for this actor.
+    #[must_use]
+    pub fn entity(&self) -> Arc<dyn EntityBase> {
+        match self {
+            Self::Player(player) => player.clone(),
+            Self::Entity(entity) => entity.clone(),
+        }
+    }
+
+    /// Returns the player if this actor is a player, otherwise `None`.
+    #[must_use]
+    pub fn as_player(&self) -> Option<&Arc<Player>> {
+        match self {
+            Self::Player(player) => Some(player),
+            Self::Entity(_) => None,
+        }
+    }
+}
+
+/// A trait implemented by events that can be produced by any actor, not just a
+/// player. Existing player events implement this in addition to `PlayerEvent`,
+/// so NPC-driven code can read the acting entity without caring whether a player
+/// or a mob enqueued the action.
+pub trait ActorEvent {
+    /// Returns the actor that produced this event.
+    fn get_actor(&self) -> Actor;
+}
+
+/// A high-level action an entity can be told to perform. [`dispatch_action`]
+/// fires a player event for `PlaceBlock`, `EnterBed` and `LeaveBed`; the other
+/// variants fire no event.
+#[derive(Clone)]
+pub enum EntityAction {
+    /// Place `block` against `against`.
+    PlaceBlock {
+        block: &'static Block,
+        against: &'static Block,
+    },
+    /// Open the container the entity is currently looking at.
+    OpenContainer,
+    /// Close the currently open container.
+    CloseContainer,
+    /// Start climbing (ladder/vine).
+    Climb,
+    /// Enter the bed at the entity's target position.
+    EnterBed { bed: &'static Block },
+    /// Leave the bed the entity is in.
+    LeaveBed { bed: &'static Block },
+    /// Follow `target` until interrupted.
+    FollowTarget { target: Arc<dyn EntityBase> },
+}
+
+/// A FIFO queue of [`EntityAction`]s attached to a single entity. One action is
+/// dequeued and run per tick; the in-flight action can be cancelled to stop the
+/// queue or interrupted to drop the current action and continue with the next.
+///
+/// The queue owns the [`Actor`] it drives, so the entity tick can pull the next
+/// action and dispatch it through the same validation/event path player input
+/// takes — see [`ActionQueue::tick`].
+pub struct ActionQueue {
+    actor: Actor,
+    actions: VecDeque<EntityAction>,
+    cancelled: AtomicBool,
+    interrupt: AtomicBool,
+}
+
+impl ActionQueue {
+    /// Creates an empty queue bound to `actor`, the entity whose actions it
+    /// drives.
+    #[must_use]
+    pub fn new(actor: Actor) -> Self {
+        Self {
+            actor,
+            actions: VecDeque::new(),
+            cancelled: AtomicBool::new(false),
+            interrupt: AtomicBool::new(false),
+        }
+    }
+
+    /// Returns the actor this queue drives.
+    #[must_use]
+    pub fn actor(&self) -> &Actor {
+        &self.actor
+    }
+
+    /// Enqueues an action to be run on a future tick.
+    pub fn push(&mut self, action: EntityAction) {
+        self.actions.push_back(action);
+    }
+
+    /// Cancels the queue entirely; no further actions will be dequeued.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the queue has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Interrupts the current action: the next [`ActionQueue::next`] drops the
+    /// head of the queue and resumes with the following action.
+    pub fn interrupt(&self) {
+        self.interrupt.store(true, Ordering::Relaxed);
+    }
+
+    /// Dequeues the next action to run this tick, or `None` if the queue is
+    /// empty or cancelled. Honors a pending interrupt by discarding the head
+    /// before returning.
+    pub fn next(&mut self) -> Option<EntityAction> {
+        if self.is_cancelled() {
+            return None;
+        }
+        if self.interrupt.swap(false, Ordering::Relaxed) {
+            self.actions.pop_front();
+        }
+        self.actions.pop_front()
+    }
+
+    /// Services the queue for one tick: dequeues at most one action (honoring a
+    /// pending interrupt or cancellation) and returns it paired with the acting
+    /// entity, so the entity tick can run it through the same validation/event
+    /// dispatch as equivalent player input. Returns `None` when nothing is due.
+    pub fn tick(&mut self) -> Option<(Actor, EntityAction)> {
+        let action = self.next()?;
+        Some((self.actor.clone(), action))
+    }
+
+    /// Drives the queue for one tick and dispatches the dequeued action through
+    /// the plugin event bus, returning `true` when the action was not cancelled
+    /// by a listener (and so should be applied by the caller), `false` when it
+    /// was cancelled, and `None` when nothing was due this tick.
+    ///
+    /// Dispatch goes through [`dispatch_action`]: an event is fired only for a
+    /// player actor and only for block placement and bed enter/leave. For a
+    /// non-player actor, or any other action, no event is fired and the result
+    /// is `Some(true)`.
+    pub async fn run_next(&mut self) -> Option<bool> {
+        let (actor, action) = self.tick()?;
+        Some(dispatch_action(&actor, &action).await)
+    }
+}
+
+/// Fires the player event that corresponds to `action` for `actor` through the
+/// plugin manager, returning `true` when the action should proceed (no listener
+/// cancelled it) and `false` when it was cancelled.
+///
+/// Only player actors have events, and only the action variants with an event
+/// (block placement and bed enter/leave) are dispatched; every other actor or
+/// variant fires no event and proceeds unconditionally.
+pub async fn dispatch_action(actor: &Actor, action: &EntityAction) -> bool {
+    use crate::plugin::api::events::block::block_place::BlockPlaceEvent;
+    use crate::plugin::api::events::player::player_bed_enter::{
+        BedEnterResult, PlayerBedEnterEvent,
+    };
+    use crate::plugin::api::events::player::player_bed_leave::PlayerBedLeaveEvent;
+
+    let Some(player) = actor.as_player() else {
+        return true;
+    };
+
+    let manager = crate::PLUGIN_MANAGER.read().await;
+    match action {
+        EntityAction::PlaceBlock { block, against } => {
+            !manager
+                .fire(BlockPlaceEvent::new(player.clone(), *block, *against, true))
+                .await
+                .cancelled
+        }
+        EntityAction::EnterBed { bed } => {
+            !manager
+                .fire(PlayerBedEnterEvent::new(
+                    player.clone(),
+                    (**bed).clone(),
+                    BedEnterResult::Ok,
+                ))
+                .await
+                .cancelled
+        }
+        EntityAction::LeaveBed { bed } => {
+            !manager
+                .fire(PlayerBedLeaveEvent::new(
+                    player.clone(),
+                    (**bed).clone(),
+                    true,
+                ))
+                .await
+                .cancelled
+        }
+        EntityAction::OpenContainer
+        | EntityAction::CloseContainer
+        | EntityAction::Climb
+        | EntityAction::FollowTarget { .. } => true,
+    }
+}
+
+impl ActionQueue {
+    /// Returns the number of actions still queued.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.actions.len()
+    }
+
+    /// Returns whether the queue is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.actions.is_empty()
+    }
+}
@@ -68,6 +68,12 @@ impl PlayerEvent for BlockPlaceEvent {
     }
 }
 
+impl crate::plugin::api::action::ActorEvent for BlockPlaceEvent {
+    fn get_actor(&self) -> crate::plugin::api::action::Actor {
+        crate::plugin::api::action::Actor::Player(self.player.clone())
+    }
+}
+
 impl BlockEvent for BlockPlaceEvent {
     fn get_block(&self) -> &Block {
         self.block_placed
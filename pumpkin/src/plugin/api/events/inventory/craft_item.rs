@@ -0,0 +1,90 @@
+use crate::entity::player::Player;
+use crate::plugin::inventory::crafting::MatchedRecipe;
+use crate::plugin::inventory::{InventoryAction, InventoryType};
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+/// Fired when a player actually takes from the result slot of a crafting grid,
+/// i.e. on an [`InventoryAction::PickupAll`] or
+/// [`InventoryAction::MoveToOtherInventory`] over a `SlotType::Result` slot.
+///
+/// Cancelling the event prevents the craft and leaves the matrix untouched.
+///
+/// Shift-click mass-crafting fires this event once per crafted batch with
+/// `multiplier` set to the number of results produced in that batch, so a plugin
+/// sees one event per batch rather than one per item.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct CraftItemEvent {
+    /// The player taking the crafted item.
+    pub player: Arc<Player>,
+
+    /// The kind of inventory the craft happened in.
+    pub inventory_type: InventoryType,
+
+    /// The action that triggered the craft.
+    pub action: InventoryAction,
+
+    /// The contents of the crafting matrix at the moment of the craft.
+    pub matrix: Vec<Option<ItemStack>>,
+
+    /// The recipe that was matched.
+    pub recipe: MatchedRecipe,
+
+    /// The result item produced for a single craft. Mutating this changes what
+    /// the player receives.
+    pub result: ItemStack,
+
+    /// The number of results produced in this batch (the recipe's per-craft
+    /// result count for a normal craft, that count times the number of crafts
+    /// for a shift-click mass-craft).
+    pub multiplier: u32,
+}
+
+#[allow(clippy::too_many_arguments)]
+impl CraftItemEvent {
+    pub fn new(
+        player: Arc<Player>,
+        inventory_type: InventoryType,
+        action: InventoryAction,
+        matrix: Vec<Option<ItemStack>>,
+        recipe: MatchedRecipe,
+        result: ItemStack,
+        multiplier: u32,
+    ) -> Self {
+        Self {
+            player,
+            inventory_type,
+            action,
+            matrix,
+            recipe,
+            result,
+            multiplier,
+            cancelled: false,
+        }
+    }
+
+    /// Returns the matched recipe.
+    #[must_use]
+    pub fn get_recipe(&self) -> &MatchedRecipe {
+        &self.recipe
+    }
+
+    /// Returns the result item produced for a single craft.
+    #[must_use]
+    pub fn get_result(&self) -> &ItemStack {
+        &self.result
+    }
+
+    /// Replaces the result item handed to the player.
+    pub fn set_result(&mut self, result: ItemStack) {
+        self.result = result;
+    }
+
+    /// Returns how many results are produced in this batch.
+    #[must_use]
+    pub fn get_multiplier(&self) -> u32 {
+        self.multiplier
+    }
+}
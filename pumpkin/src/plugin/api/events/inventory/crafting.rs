@@ -0,0 +1,158 @@
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+use crate::plugin::inventory::craft_item::CraftItemEvent;
+use crate::plugin::inventory::inventory_click::InventoryClickEvent;
+use crate::plugin::inventory::prepare_item_craft::PrepareItemCraftEvent;
+use crate::plugin::inventory::{InventoryAction, InventoryType, SlotType};
+
+/// A matched crafting recipe, regardless of whether it is a vanilla
+/// shaped/shapeless recipe or a plugin-registered station recipe.
+///
+/// The crafting events carry this so plugins can inspect what the server
+/// matched without having to re-run recipe resolution themselves.
+#[derive(Clone)]
+pub struct MatchedRecipe {
+    /// The namespaced id of the recipe (e.g. `minecraft:stick`).
+    pub id: String,
+    /// The item that the recipe yields for a single craft.
+    pub result: ItemStack,
+    /// How many of `result` a single craft produces.
+    pub result_count: u32,
+}
+
+/// A family of custom, non-vanilla recipes bound to a particular station
+/// inventory (e.g. a chemistry bench). Plugins register implementations so they
+/// can define benches that consume several reagents to yield one product
+/// without touching core inventory code.
+pub trait StationRecipeFamily: Send + Sync {
+    /// The namespaced id of this recipe family.
+    fn id(&self) -> &str;
+
+    /// Attempts to match `matrix` (the station's input grid, row-major) against
+    /// this family, returning the matched recipe on success.
+    fn matches(&self, matrix: &[Option<ItemStack>]) -> Option<MatchedRecipe>;
+}
+
+/// Holds the custom station recipe families a server knows about. Lookups walk
+/// the registered families in insertion order and return the first match.
+#[derive(Default)]
+pub struct StationRecipeRegistry {
+    families: Vec<Arc<dyn StationRecipeFamily>>,
+}
+
+impl StationRecipeRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom station recipe family.
+    pub fn register(&mut self, family: Arc<dyn StationRecipeFamily>) {
+        self.families.push(family);
+    }
+
+    /// Returns the first registered family that matches `matrix`, if any.
+    #[must_use]
+    pub fn match_recipe(&self, matrix: &[Option<ItemStack>]) -> Option<MatchedRecipe> {
+        self.families.iter().find_map(|family| family.matches(matrix))
+    }
+
+    /// Builds the [`PrepareItemCraftEvent`] to fire whenever `player`'s crafting
+    /// grid changes: the registry re-matches `matrix` and seeds the previewed
+    /// result from the matched recipe (or `None` when nothing matches). The
+    /// returned event is not fired; see [`Self::fire_prepare`].
+    #[must_use]
+    pub fn prepare_for_matrix(
+        &self,
+        player: Arc<Player>,
+        inventory_type: InventoryType,
+        matrix: Vec<Option<ItemStack>>,
+    ) -> PrepareItemCraftEvent {
+        let recipe = self.match_recipe(&matrix);
+        let result = recipe.as_ref().map(|recipe| recipe.result);
+        PrepareItemCraftEvent::new(player, inventory_type, matrix, recipe, result)
+    }
+
+    /// Builds the [`CraftItemEvent`] for a click on a crafting result slot, or
+    /// `None` when `click` is not a craft (wrong slot/action, or no recipe
+    /// matches). The returned event is not fired; see [`Self::fire_craft`].
+    ///
+    /// A shift-click ([`InventoryAction::MoveToOtherInventory`]) mass-crafts, so
+    /// `multiplier` is the number of results the whole batch yields; an ordinary
+    /// pickup yields a single craft's worth.
+    #[must_use]
+    pub fn craft_for_click(
+        &self,
+        click: &InventoryClickEvent,
+        inventory_type: InventoryType,
+        matrix: Vec<Option<ItemStack>>,
+    ) -> Option<CraftItemEvent> {
+        if !matches!(click.slot_type, SlotType::Result) {
+            return None;
+        }
+        if !matches!(
+            click.action,
+            InventoryAction::PickupAll | InventoryAction::MoveToOtherInventory
+        ) {
+            return None;
+        }
+
+        let recipe = self.match_recipe(&matrix)?;
+        let multiplier = match click.action {
+            InventoryAction::MoveToOtherInventory => {
+                batches_in_matrix(&matrix).max(1) * recipe.result_count
+            }
+            _ => recipe.result_count,
+        };
+        let result = recipe.result;
+        Some(CraftItemEvent::new(
+            click.player.clone(),
+            inventory_type,
+            click.action.clone(),
+            matrix,
+            recipe,
+            result,
+            multiplier,
+        ))
+    }
+
+    /// Builds the crafting preview for `player`'s current grid and fires it
+    /// through the plugin event bus, returning the fired event with any `result`
+    /// substitution listeners made.
+    pub async fn fire_prepare(
+        &self,
+        player: Arc<Player>,
+        inventory_type: InventoryType,
+        matrix: Vec<Option<ItemStack>>,
+    ) -> PrepareItemCraftEvent {
+        let event = self.prepare_for_matrix(player, inventory_type, matrix);
+        crate::PLUGIN_MANAGER.read().await.fire(event).await
+    }
+
+    /// Builds the craft event for a result-slot `click` and fires it through the
+    /// plugin event bus, returning the fired event, or `None` when the click is
+    /// not a craft. The craft itself is not applied here; the returned event
+    /// carries the listeners' `cancelled` flag and any `result` change.
+    pub async fn fire_craft(
+        &self,
+        click: &InventoryClickEvent,
+        inventory_type: InventoryType,
+        matrix: Vec<Option<ItemStack>>,
+    ) -> Option<CraftItemEvent> {
+        let event = self.craft_for_click(click, inventory_type, matrix)?;
+        Some(crate::PLUGIN_MANAGER.read().await.fire(event).await)
+    }
+}
+
+/// Number of full crafts a shift-click can drain from `matrix`: the smallest
+/// stack among the occupied input slots. Returns `0` for an empty matrix.
+fn batches_in_matrix(matrix: &[Option<ItemStack>]) -> u32 {
+    matrix
+        .iter()
+        .filter_map(|slot| slot.as_ref())
+        .map(|stack| u32::from(stack.item_count))
+        .min()
+        .unwrap_or(0)
+}
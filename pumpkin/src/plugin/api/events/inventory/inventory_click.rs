@@ -131,3 +131,9 @@ impl InventoryClickEvent {
         self.current
     }
 }
+
+impl crate::plugin::api::action::ActorEvent for InventoryClickEvent {
+    fn get_actor(&self) -> crate::plugin::api::action::Actor {
+        crate::plugin::api::action::Actor::Player(self.player.clone())
+    }
+}
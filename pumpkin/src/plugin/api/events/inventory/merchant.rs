@@ -0,0 +1,231 @@
+use crate::entity::player::Player;
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+/// A single trade offer exposed by a merchant inventory.
+#[derive(Clone)]
+pub struct MerchantOffer {
+    /// The primary input item the trade costs.
+    pub first_input: ItemStack,
+    /// The optional second input item the trade costs.
+    pub second_input: Option<ItemStack>,
+    /// The item the trade yields.
+    pub result: ItemStack,
+    /// How many times the offer can be used before it locks.
+    pub max_uses: u32,
+    /// How many times the offer has already been used.
+    pub uses: u32,
+    /// Whether the offer is currently disabled (e.g. out of stock).
+    pub disabled: bool,
+}
+
+impl MerchantOffer {
+    /// Read-only "inspect for sale" view of this offer's metadata, suitable for
+    /// rendering a catalog or confirmation prompt without committing a purchase.
+    #[must_use]
+    pub fn info(&self) -> MerchantOfferInfo {
+        MerchantOfferInfo {
+            first_cost: self.first_input,
+            second_cost: self.second_input,
+            result: self.result,
+            max_uses: self.max_uses,
+            uses: self.uses,
+            disabled: self.disabled || self.uses >= self.max_uses,
+        }
+    }
+}
+
+/// Read-only metadata for a merchant offer, returned by the inspect query.
+#[derive(Clone)]
+pub struct MerchantOfferInfo {
+    pub first_cost: ItemStack,
+    pub second_cost: Option<ItemStack>,
+    pub result: ItemStack,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub disabled: bool,
+}
+
+/// Returns the metadata for every offer in `offers` without committing a trade,
+/// so shop plugins can render catalogs and confirmation prompts.
+#[must_use]
+pub fn inspect_for_sale(offers: &[MerchantOffer]) -> Vec<MerchantOfferInfo> {
+    offers.iter().map(MerchantOffer::info).collect()
+}
+
+/// The merchant side of a trade screen: the source of the offers these events
+/// carry. The `from_merchant` constructors build the events from an
+/// implementor rather than from a bare offer slice.
+pub trait MerchantInventory: Send + Sync {
+    /// The offers this merchant is currently presenting.
+    fn offers(&self) -> Vec<MerchantOffer>;
+}
+
+/// Fired when a merchant inventory is opened, exposing its trade offer list.
+#[derive(Event, Clone)]
+pub struct MerchantInventoryOpenEvent {
+    /// The player opening the merchant inventory.
+    pub player: Arc<Player>,
+
+    /// The offers the merchant is presenting.
+    pub offers: Vec<MerchantOffer>,
+}
+
+impl MerchantInventoryOpenEvent {
+    pub fn new(player: Arc<Player>, offers: Vec<MerchantOffer>) -> Self {
+        Self { player, offers }
+    }
+
+    /// Builds the open event for `merchant`, to fire when `player` opens it.
+    #[must_use]
+    pub fn from_merchant(player: Arc<Player>, merchant: &dyn MerchantInventory) -> Self {
+        Self::new(player, merchant.offers())
+    }
+
+    /// Returns read-only metadata for every offer.
+    #[must_use]
+    pub fn inspect(&self) -> Vec<MerchantOfferInfo> {
+        inspect_for_sale(&self.offers)
+    }
+
+    /// Fires this event through the plugin event bus when the merchant screen
+    /// opens. Returns the resolved event so listeners can read the offer list.
+    pub async fn dispatch(self) -> Self {
+        crate::PLUGIN_MANAGER.read().await.fire(self).await
+    }
+}
+
+/// Fired when a player highlights a trade recipe. Cancelling prevents the
+/// selection from taking effect.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct TradeSelectEvent {
+    /// The player selecting the trade.
+    pub player: Arc<Player>,
+
+    /// The index of the selected offer within the merchant's offer list.
+    pub selected: usize,
+
+    /// The offers the merchant is presenting.
+    pub offers: Vec<MerchantOffer>,
+}
+
+impl TradeSelectEvent {
+    pub fn new(player: Arc<Player>, selected: usize, offers: Vec<MerchantOffer>) -> Self {
+        Self {
+            player,
+            selected,
+            offers,
+            cancelled: false,
+        }
+    }
+
+    /// Builds the select event for `merchant`, to fire when `player` highlights
+    /// the offer at `selected`.
+    #[must_use]
+    pub fn from_merchant(
+        player: Arc<Player>,
+        merchant: &dyn MerchantInventory,
+        selected: usize,
+    ) -> Self {
+        Self::new(player, selected, merchant.offers())
+    }
+
+    /// Returns the selected offer, if the index is in range.
+    #[must_use]
+    pub fn selected_offer(&self) -> Option<&MerchantOffer> {
+        self.offers.get(self.selected)
+    }
+
+    /// Fires this event through the plugin event bus and returns the resolved
+    /// event. Applying the selection is not done here; the returned event
+    /// carries the listeners' `cancelled` flag.
+    pub async fn dispatch(self) -> Self {
+        crate::PLUGIN_MANAGER.read().await.fire(self).await
+    }
+}
+
+/// Fired when a player completes a purchase. Cancelling blocks the trade.
+/// Plugins may adjust the price by mutating the inputs or the result.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct MerchantTradeEvent {
+    /// The player making the purchase.
+    pub player: Arc<Player>,
+
+    /// The first input item consumed by the trade.
+    pub first_input: ItemStack,
+
+    /// The optional second input item consumed by the trade.
+    pub second_input: Option<ItemStack>,
+
+    /// The item the player receives.
+    pub result: ItemStack,
+
+    /// The offer being purchased.
+    pub offer: MerchantOffer,
+}
+
+impl MerchantTradeEvent {
+    pub fn new(
+        player: Arc<Player>,
+        first_input: ItemStack,
+        second_input: Option<ItemStack>,
+        result: ItemStack,
+        offer: MerchantOffer,
+    ) -> Self {
+        Self {
+            player,
+            first_input,
+            second_input,
+            result,
+            offer,
+            cancelled: false,
+        }
+    }
+
+    /// Builds the purchase event for the offer at `selected` in `merchant`, to
+    /// fire when `player` completes the trade. Returns `None` when the index is
+    /// out of range. The event's inputs and result are seeded from the offer so
+    /// plugins can re-price the trade before it commits.
+    #[must_use]
+    pub fn from_merchant(
+        player: Arc<Player>,
+        merchant: &dyn MerchantInventory,
+        selected: usize,
+    ) -> Option<Self> {
+        let offer = merchant.offers().into_iter().nth(selected)?;
+        Some(Self::new(
+            player,
+            offer.first_input,
+            offer.second_input,
+            offer.result,
+            offer,
+        ))
+    }
+
+    /// Returns the offer being purchased.
+    #[must_use]
+    pub fn get_offer(&self) -> &MerchantOffer {
+        &self.offer
+    }
+
+    /// Returns the item the player will receive.
+    #[must_use]
+    pub fn get_result(&self) -> &ItemStack {
+        &self.result
+    }
+
+    /// Overrides the item the player will receive.
+    pub fn set_result(&mut self, result: ItemStack) {
+        self.result = result;
+    }
+
+    /// Fires this event through the plugin event bus and returns the resolved
+    /// event. Committing the trade is not done here; the returned event carries
+    /// the listeners' `cancelled` flag and any re-priced inputs or result.
+    pub async fn dispatch(self) -> Self {
+        crate::PLUGIN_MANAGER.read().await.fire(self).await
+    }
+}
@@ -1,6 +1,10 @@
 //use pumpkin_world::inventory::Inventory;
 
+pub mod craft_item;
+pub mod crafting;
 pub mod inventory_click;
+pub mod merchant;
+pub mod prepare_item_craft;
 
 /* /// A trait representing events related to inventories.
 ///
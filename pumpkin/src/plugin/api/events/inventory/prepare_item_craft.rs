@@ -0,0 +1,67 @@
+use crate::entity::player::Player;
+use crate::plugin::inventory::InventoryType;
+use crate::plugin::inventory::crafting::MatchedRecipe;
+use pumpkin_macros::Event;
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+/// Fired whenever a crafting grid changes and the `SlotType::Result` preview is
+/// recomputed.
+///
+/// This event is not cancellable, but plugins may substitute or clear the
+/// previewed result by mutating `result`; clearing it (setting `None`) hides the
+/// preview entirely.
+#[derive(Event, Clone)]
+pub struct PrepareItemCraftEvent {
+    /// The player whose crafting grid changed.
+    pub player: Arc<Player>,
+
+    /// The kind of inventory the crafting happened in (Workbench, Crafting,
+    /// Smithing or Brewing).
+    pub inventory_type: InventoryType,
+
+    /// The contents of the crafting matrix, row-major, one entry per input slot.
+    pub matrix: Vec<Option<ItemStack>>,
+
+    /// The recipe the server matched against the matrix, if any.
+    pub recipe: Option<MatchedRecipe>,
+
+    /// The previewed result item shown in the result slot. Mutating this swaps
+    /// the preview; setting it to `None` clears it.
+    pub result: Option<ItemStack>,
+}
+
+impl PrepareItemCraftEvent {
+    pub fn new(
+        player: Arc<Player>,
+        inventory_type: InventoryType,
+        matrix: Vec<Option<ItemStack>>,
+        recipe: Option<MatchedRecipe>,
+        result: Option<ItemStack>,
+    ) -> Self {
+        Self {
+            player,
+            inventory_type,
+            matrix,
+            recipe,
+            result,
+        }
+    }
+
+    /// Returns the matched recipe, if any.
+    #[must_use]
+    pub fn get_recipe(&self) -> Option<&MatchedRecipe> {
+        self.recipe.as_ref()
+    }
+
+    /// Returns the currently previewed result item.
+    #[must_use]
+    pub fn get_result(&self) -> Option<&ItemStack> {
+        self.result.as_ref()
+    }
+
+    /// Replaces the previewed result item.
+    pub fn set_result(&mut self, result: Option<ItemStack>) {
+        self.result = result;
+    }
+}
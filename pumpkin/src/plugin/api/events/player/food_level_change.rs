@@ -0,0 +1,92 @@
+use pumpkin_macros::{Event, cancellable};
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// What caused a player's food level to change.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum FoodLevelChangeTrigger {
+    /// Scheduler-driven passive decay.
+    Decay,
+    /// Regeneration or depletion from activity (sprinting, jumping, healing).
+    Exhaustion,
+    /// The player ate or drank something.
+    Consumption,
+    /// A plugin changed the level directly.
+    Plugin,
+}
+
+/// Fired when a player's food level is about to change.
+///
+/// Cancelling the event leaves the food level untouched. Plugins may also clamp
+/// the change by mutating `new_level` before it is applied.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct FoodLevelChangeEvent {
+    /// The player whose food level is changing.
+    pub player: Arc<Player>,
+
+    /// The food level before the change.
+    pub old_level: u32,
+
+    /// The food level that will be applied unless cancelled.
+    pub new_level: u32,
+
+    /// What triggered the change.
+    pub trigger: FoodLevelChangeTrigger,
+}
+
+impl FoodLevelChangeEvent {
+    pub fn new(
+        player: Arc<Player>,
+        old_level: u32,
+        new_level: u32,
+        trigger: FoodLevelChangeTrigger,
+    ) -> Self {
+        Self {
+            player,
+            old_level,
+            new_level,
+            trigger,
+            cancelled: false,
+        }
+    }
+
+    /// Returns the food level before the change.
+    #[must_use]
+    pub fn get_old_level(&self) -> u32 {
+        self.old_level
+    }
+
+    /// Returns the food level that will be applied.
+    #[must_use]
+    pub fn get_new_level(&self) -> u32 {
+        self.new_level
+    }
+
+    /// Overrides the food level that will be applied.
+    pub fn set_new_level(&mut self, new_level: u32) {
+        self.new_level = new_level;
+    }
+
+    /// Returns what triggered the change.
+    #[must_use]
+    pub fn get_trigger(&self) -> FoodLevelChangeTrigger {
+        self.trigger
+    }
+
+    /// Fires this event through the plugin event bus and returns the resolved
+    /// event. The food level is not changed here; the returned event carries the
+    /// listeners' `cancelled` flag and any clamped `new_level`.
+    pub async fn dispatch(self) -> Self {
+        crate::PLUGIN_MANAGER.read().await.fire(self).await
+    }
+}
+
+impl PlayerEvent for FoodLevelChangeEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
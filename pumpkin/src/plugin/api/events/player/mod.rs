@@ -0,0 +1,9 @@
+pub mod food_level_change;
+pub mod player_bed_enter;
+pub mod player_bed_leave;
+pub mod player_death;
+pub mod player_item_consume;
+pub mod player_respawn;
+pub mod urges;
+
+pub use crate::plugin::player::PlayerEvent;
@@ -69,6 +69,12 @@ impl PlayerEvent for PlayerBedEnterEvent {
     }
 }
 
+impl crate::plugin::api::action::ActorEvent for PlayerBedEnterEvent {
+    fn get_actor(&self) -> crate::plugin::api::action::Actor {
+        crate::plugin::api::action::Actor::Player(self.player.clone())
+    }
+}
+
 /// The possible results of a player trying to enter a bed.
 #[derive(Clone)]
 pub enum BedEnterResult {
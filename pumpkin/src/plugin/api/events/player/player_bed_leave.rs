@@ -59,3 +59,9 @@ impl PlayerEvent for PlayerBedLeaveEvent {
         &self.player
     }
 }
+
+impl crate::plugin::api::action::ActorEvent for PlayerBedLeaveEvent {
+    fn get_actor(&self) -> crate::plugin::api::action::Actor {
+        crate::plugin::api::action::Actor::Player(self.player.clone())
+    }
+}
@@ -0,0 +1,66 @@
+use pumpkin_macros::{Event, cancellable};
+use pumpkin_world::item::ItemStack;
+use std::sync::Arc;
+
+use crate::entity::player::Player;
+
+use super::PlayerEvent;
+
+/// Fired when a player finishes eating or drinking an item.
+///
+/// Cancelling the event prevents the item from being consumed. For container
+/// items (e.g. a potion leaving behind a bottle) `replacement` holds the item
+/// the consumed stack is replaced with; plugins may override it.
+#[cancellable]
+#[derive(Event, Clone)]
+pub struct PlayerItemConsumeEvent {
+    /// The player consuming the item.
+    pub player: Arc<Player>,
+
+    /// The item being eaten or drunk.
+    pub item: ItemStack,
+
+    /// The item that replaces the consumed stack, if any (e.g. an empty bottle).
+    pub replacement: Option<ItemStack>,
+}
+
+impl PlayerItemConsumeEvent {
+    pub fn new(player: Arc<Player>, item: ItemStack, replacement: Option<ItemStack>) -> Self {
+        Self {
+            player,
+            item,
+            replacement,
+            cancelled: false,
+        }
+    }
+
+    /// Returns the item being consumed.
+    #[must_use]
+    pub fn get_item(&self) -> &ItemStack {
+        &self.item
+    }
+
+    /// Returns the replacement item left after consumption, if any.
+    #[must_use]
+    pub fn get_replacement(&self) -> Option<&ItemStack> {
+        self.replacement.as_ref()
+    }
+
+    /// Sets the replacement item left after consumption.
+    pub fn set_replacement(&mut self, replacement: Option<ItemStack>) {
+        self.replacement = replacement;
+    }
+
+    /// Fires this event through the plugin event bus and returns the resolved
+    /// event. The item is not consumed here; the returned event carries the
+    /// listeners' `cancelled` flag and any changed `replacement`.
+    pub async fn dispatch(self) -> Self {
+        crate::PLUGIN_MANAGER.read().await.fire(self).await
+    }
+}
+
+impl PlayerEvent for PlayerItemConsumeEvent {
+    fn get_player(&self) -> &Arc<Player> {
+        &self.player
+    }
+}
@@ -0,0 +1,126 @@
+use dashmap::DashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::entity::player::Player;
+use crate::plugin::api::task::{TaskHandle, TaskHandler, TaskScheduler};
+
+/// Resolves the currently online players the decay job should tick. Supplied by
+/// the server so the registry does not need to reach into world state itself.
+pub type OnlinePlayers = Arc<dyn Fn() -> Vec<Arc<Player>> + Send + Sync>;
+
+/// Handler invoked when an urge decays past its configured threshold for a
+/// player. Receives the player and the urge's current value.
+pub type UrgeThresholdHandler = Arc<dyn Fn(&Arc<Player>, f32) + Send + Sync>;
+
+/// A plugin-defined "urge" stat (e.g. thirst) that decays over time alongside
+/// the built-in hunger/saturation stats.
+#[derive(Clone)]
+pub struct Urge {
+    /// Unique name of the urge.
+    pub name: String,
+    /// Amount subtracted from the value each decay tick.
+    pub per_tick_decrement: f32,
+    /// Inclusive `(min, max)` clamp applied after each decrement.
+    pub clamp: (f32, f32),
+    /// The value at or below which `handler` fires.
+    pub threshold: f32,
+    /// Invoked when the value first decays to at or below `threshold`.
+    pub handler: UrgeThresholdHandler,
+}
+
+/// Registry of custom urges plus the per-player values the decay job maintains.
+///
+/// The decay job is driven by the task scheduler (see
+/// [`UrgeRegistry::schedule_decay`]) and only touches players that are
+/// currently online.
+#[derive(Default)]
+pub struct UrgeRegistry {
+    urges: Vec<Urge>,
+    /// `(player uuid, urge name) -> current value`.
+    values: DashMap<(Uuid, String), f32>,
+}
+
+impl UrgeRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a custom urge. Newly seen players start at the urge's clamp
+    /// maximum.
+    pub fn register(&mut self, urge: Urge) {
+        self.urges.push(urge);
+    }
+
+    /// Returns the current value of `urge` for `player`, if it has been
+    /// initialized.
+    #[must_use]
+    pub fn value(&self, player: Uuid, urge: &str) -> Option<f32> {
+        self.values
+            .get(&(player, urge.to_string()))
+            .map(|v| *v)
+    }
+
+    /// Decays every registered urge for the supplied online players by one tick,
+    /// firing threshold handlers as values cross their configured point.
+    ///
+    /// Offline players are simply not included in `online_players`, so their
+    /// values are left untouched.
+    pub fn decay_tick(&self, online_players: &[Arc<Player>]) {
+        for player in online_players {
+            let uuid = player.gameprofile.id;
+            for urge in &self.urges {
+                let key = (uuid, urge.name.clone());
+                let mut entry = self.values.entry(key).or_insert(urge.clamp.1);
+
+                let was_above = *entry > urge.threshold;
+                *entry = (*entry - urge.per_tick_decrement).clamp(urge.clamp.0, urge.clamp.1);
+                let now_at_or_below = *entry <= urge.threshold;
+
+                if was_above && now_at_or_below {
+                    let value = *entry;
+                    // Drop the map guard before invoking the handler so it may
+                    // read other urges without deadlocking.
+                    drop(entry);
+                    (urge.handler)(player, value);
+                }
+            }
+        }
+    }
+
+    /// Registers the decay job on `scheduler` so every `interval_ticks` the
+    /// registry decrements each urge for the players returned by
+    /// `online_players`, firing threshold handlers as values cross their point.
+    ///
+    /// Returns the [`TaskHandle`] so the caller can cancel the job on shutdown.
+    pub fn schedule_decay(
+        self: Arc<Self>,
+        scheduler: &TaskScheduler,
+        interval_ticks: u64,
+        online_players: OnlinePlayers,
+    ) -> TaskHandle {
+        scheduler.schedule_interval(
+            interval_ticks,
+            Arc::new(UrgeDecayTask {
+                registry: self,
+                online_players,
+            }),
+        )
+    }
+}
+
+/// The scheduler task that drives [`UrgeRegistry::decay_tick`] each interval.
+struct UrgeDecayTask {
+    registry: Arc<UrgeRegistry>,
+    online_players: OnlinePlayers,
+}
+
+#[async_trait::async_trait]
+impl TaskHandler for UrgeDecayTask {
+    async fn run(&self) {
+        self.registry.decay_tick(&(self.online_players)());
+    }
+
+    async fn cancel(&self) {}
+}
@@ -0,0 +1,784 @@
+//! Human-readable and binary export/import for persistent data containers.
+//!
+//! The whole container is serialized as a plist-like typed tree: every
+//! [`PersistentDataType`] variant maps onto a self-describing element, so the
+//! dump round-trips losslessly (including `Bytes` and nested `List`s) and can be
+//! inspected or hand-edited by admins. Two encodings share the same tree:
+//! [`Format::Xml`] for a diffable text form and [`Format::Binary`] for a compact
+//! on-disk form.
+
+use crate::plugin::persistence::{
+    NamespacedKey, NestedContainer, PersistentDataContainer, PersistentDataType,
+};
+
+/// The serialization format used by [`export`]/[`import`].
+///
+/// [`export`]: crate::plugin::persistence::PersistentDataHolder::export
+/// [`import`]: crate::plugin::persistence::PersistentDataHolder::import
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// A human-readable XML document.
+    Xml,
+    /// A compact tag-length-value binary document.
+    Binary,
+}
+
+/// Error returned when a document cannot be parsed back into a container.
+#[derive(Debug)]
+pub enum DocumentError {
+    /// The XML text was malformed.
+    Xml(String),
+    /// The binary stream was truncated or used an unknown tag.
+    Binary(String),
+}
+
+impl std::fmt::Display for DocumentError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Xml(message) => write!(f, "malformed persistent-data XML: {message}"),
+            Self::Binary(message) => write!(f, "malformed persistent-data binary: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DocumentError {}
+
+/// Serializes `container` in the given `format`.
+#[must_use]
+pub fn export(container: &PersistentDataContainer, format: Format) -> Vec<u8> {
+    match format {
+        Format::Xml => xml::write(container).into_bytes(),
+        Format::Binary => binary::write(container),
+    }
+}
+
+/// Parses a container previously produced by [`export`] in the same `format`.
+pub fn import(format: Format, data: &[u8]) -> Result<PersistentDataContainer, DocumentError> {
+    match format {
+        Format::Xml => {
+            let text = std::str::from_utf8(data)
+                .map_err(|error| DocumentError::Xml(error.to_string()))?;
+            xml::read(text)
+        }
+        Format::Binary => binary::read(data),
+    }
+}
+
+/// The binary encoding: a `PDC1` magic, a `u32` entry count, then each entry as
+/// a length-prefixed key string followed by a tagged value.
+mod binary {
+    use super::*;
+
+    const MAGIC: &[u8; 4] = b"PDC1";
+
+    // Value tags. Kept stable so older dumps keep importing.
+    const T_BOOL: u8 = 0;
+    const T_STRING: u8 = 1;
+    const T_CHAR: u8 = 2;
+    const T_I32: u8 = 3;
+    const T_I64: u8 = 4;
+    const T_U8: u8 = 5;
+    const T_U16: u8 = 6;
+    const T_U32: u8 = 7;
+    const T_U64: u8 = 8;
+    const T_F32: u8 = 9;
+    const T_F64: u8 = 10;
+    const T_BYTES: u8 = 11;
+    const T_INT_ARRAY: u8 = 12;
+    const T_LONG_ARRAY: u8 = 13;
+    const T_LIST: u8 = 14;
+    const T_CONTAINER: u8 = 15;
+    const T_COMPOUND: u8 = 16;
+
+    pub(super) fn write(container: &PersistentDataContainer) -> Vec<u8> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        write_entries(&mut out, container);
+        out
+    }
+
+    fn write_entries(out: &mut Vec<u8>, container: &PersistentDataContainer) {
+        out.extend_from_slice(&(container.len() as u32).to_le_bytes());
+        for entry in container {
+            write_str(out, &entry.key().to_string());
+            write_value(out, entry.value());
+        }
+    }
+
+    fn write_str(out: &mut Vec<u8>, value: &str) {
+        out.extend_from_slice(&(value.len() as u32).to_le_bytes());
+        out.extend_from_slice(value.as_bytes());
+    }
+
+    fn write_value(out: &mut Vec<u8>, value: &PersistentDataType) {
+        match value {
+            PersistentDataType::Bool(b) => {
+                out.push(T_BOOL);
+                out.push(u8::from(*b));
+            }
+            PersistentDataType::String(s) => {
+                out.push(T_STRING);
+                write_str(out, s);
+            }
+            PersistentDataType::Char(c) => {
+                out.push(T_CHAR);
+                out.extend_from_slice(&(*c as u32).to_le_bytes());
+            }
+            PersistentDataType::I32(v) => {
+                out.push(T_I32);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::I64(v) => {
+                out.push(T_I64);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::U8(v) => {
+                out.push(T_U8);
+                out.push(*v);
+            }
+            PersistentDataType::U16(v) => {
+                out.push(T_U16);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::U32(v) => {
+                out.push(T_U32);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::U64(v) => {
+                out.push(T_U64);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::F32(v) => {
+                out.push(T_F32);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::F64(v) => {
+                out.push(T_F64);
+                out.extend_from_slice(&v.to_le_bytes());
+            }
+            PersistentDataType::Bytes(bytes) => {
+                out.push(T_BYTES);
+                out.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+                out.extend_from_slice(bytes);
+            }
+            PersistentDataType::IntArray(ints) => {
+                out.push(T_INT_ARRAY);
+                out.extend_from_slice(&(ints.len() as u32).to_le_bytes());
+                for v in ints {
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            PersistentDataType::LongArray(longs) => {
+                out.push(T_LONG_ARRAY);
+                out.extend_from_slice(&(longs.len() as u32).to_le_bytes());
+                for v in longs {
+                    out.extend_from_slice(&v.to_le_bytes());
+                }
+            }
+            PersistentDataType::List(items) => {
+                out.push(T_LIST);
+                out.extend_from_slice(&(items.len() as u32).to_le_bytes());
+                for item in items {
+                    write_value(out, item);
+                }
+            }
+            PersistentDataType::Container(NestedContainer(nested)) => {
+                out.push(T_CONTAINER);
+                write_entries(out, nested);
+            }
+            PersistentDataType::Compound(map) => {
+                out.push(T_COMPOUND);
+                out.extend_from_slice(&(map.len() as u32).to_le_bytes());
+                for (key, value) in map {
+                    write_str(out, key);
+                    write_value(out, value);
+                }
+            }
+        }
+    }
+
+    /// A cursor over the input that tracks a position and reports truncation as a
+    /// [`DocumentError::Binary`].
+    struct Cursor<'a> {
+        data: &'a [u8],
+        pos: usize,
+    }
+
+    impl<'a> Cursor<'a> {
+        fn take(&mut self, n: usize) -> Result<&'a [u8], DocumentError> {
+            let end = self.pos.checked_add(n).filter(|end| *end <= self.data.len());
+            match end {
+                Some(end) => {
+                    let slice = &self.data[self.pos..end];
+                    self.pos = end;
+                    Ok(slice)
+                }
+                None => Err(DocumentError::Binary("unexpected end of input".to_string())),
+            }
+        }
+
+        fn u8(&mut self) -> Result<u8, DocumentError> {
+            Ok(self.take(1)?[0])
+        }
+
+        fn u32(&mut self) -> Result<u32, DocumentError> {
+            Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+        }
+
+        fn u64(&mut self) -> Result<u64, DocumentError> {
+            Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+        }
+
+        fn string(&mut self) -> Result<String, DocumentError> {
+            let len = self.u32()? as usize;
+            let bytes = self.take(len)?;
+            String::from_utf8(bytes.to_vec())
+                .map_err(|error| DocumentError::Binary(error.to_string()))
+        }
+    }
+
+    pub(super) fn read(data: &[u8]) -> Result<PersistentDataContainer, DocumentError> {
+        let mut cursor = Cursor { data, pos: 0 };
+        if cursor.take(4)? != MAGIC {
+            return Err(DocumentError::Binary("bad magic".to_string()));
+        }
+        read_entries(&mut cursor)
+    }
+
+    fn read_entries(cursor: &mut Cursor) -> Result<PersistentDataContainer, DocumentError> {
+        let container = PersistentDataContainer::new();
+        let count = cursor.u32()?;
+        for _ in 0..count {
+            let key = cursor.string()?;
+            let value = read_value(cursor)?;
+            container.insert(parse_key(&key), value);
+        }
+        Ok(container)
+    }
+
+    fn read_value(cursor: &mut Cursor) -> Result<PersistentDataType, DocumentError> {
+        Ok(match cursor.u8()? {
+            T_BOOL => PersistentDataType::Bool(cursor.u8()? != 0),
+            T_STRING => PersistentDataType::String(cursor.string()?),
+            T_CHAR => char::from_u32(cursor.u32()?)
+                .map(PersistentDataType::Char)
+                .ok_or_else(|| DocumentError::Binary("invalid char".to_string()))?,
+            T_I32 => PersistentDataType::I32(cursor.u32()? as i32),
+            T_I64 => PersistentDataType::I64(cursor.u64()? as i64),
+            T_U8 => PersistentDataType::U8(cursor.u8()?),
+            T_U16 => PersistentDataType::U16(
+                u16::from_le_bytes(cursor.take(2)?.try_into().unwrap()),
+            ),
+            T_U32 => PersistentDataType::U32(cursor.u32()?),
+            T_U64 => PersistentDataType::U64(cursor.u64()?),
+            T_F32 => PersistentDataType::F32(f32::from_le_bytes(cursor.take(4)?.try_into().unwrap())),
+            T_F64 => PersistentDataType::F64(f64::from_le_bytes(cursor.take(8)?.try_into().unwrap())),
+            T_BYTES => {
+                let len = cursor.u32()? as usize;
+                PersistentDataType::Bytes(cursor.take(len)?.to_vec().into_boxed_slice())
+            }
+            T_INT_ARRAY => {
+                let count = cursor.u32()?;
+                let mut ints = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    ints.push(cursor.u32()? as i32);
+                }
+                PersistentDataType::IntArray(ints)
+            }
+            T_LONG_ARRAY => {
+                let count = cursor.u32()?;
+                let mut longs = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    longs.push(cursor.u64()? as i64);
+                }
+                PersistentDataType::LongArray(longs)
+            }
+            T_LIST => {
+                let count = cursor.u32()?;
+                let mut items = Vec::with_capacity(count as usize);
+                for _ in 0..count {
+                    items.push(read_value(cursor)?);
+                }
+                PersistentDataType::List(items)
+            }
+            T_CONTAINER => {
+                PersistentDataType::Container(NestedContainer(read_entries(cursor)?))
+            }
+            T_COMPOUND => {
+                let count = cursor.u32()?;
+                let mut map = std::collections::HashMap::with_capacity(count as usize);
+                for _ in 0..count {
+                    let key = cursor.string()?;
+                    map.insert(key, read_value(cursor)?);
+                }
+                PersistentDataType::Compound(map)
+            }
+            tag => {
+                return Err(DocumentError::Binary(format!("unknown value tag {tag}")));
+            }
+        })
+    }
+}
+
+/// The XML encoding: a `<pdc>` root whose `<entry key="namespace:key">` children
+/// each wrap a single typed element.
+mod xml {
+    use super::*;
+    use std::fmt::Write;
+
+    pub(super) fn write(container: &PersistentDataContainer) -> String {
+        let mut out = String::from("<pdc>\n");
+        write_entries(&mut out, container, 1);
+        out.push_str("</pdc>\n");
+        out
+    }
+
+    fn indent(out: &mut String, depth: usize) {
+        for _ in 0..depth {
+            out.push_str("  ");
+        }
+    }
+
+    fn write_entries(out: &mut String, container: &PersistentDataContainer, depth: usize) {
+        for entry in container {
+            indent(out, depth);
+            let _ = write!(out, "<entry key=\"{}\">", escape(&entry.key().to_string()));
+            write_value(out, entry.value(), depth);
+            out.push_str("</entry>\n");
+        }
+    }
+
+    fn write_value(out: &mut String, value: &PersistentDataType, depth: usize) {
+        match value {
+            PersistentDataType::Bool(b) => {
+                let _ = write!(out, "<bool>{b}</bool>");
+            }
+            PersistentDataType::String(s) => {
+                let _ = write!(out, "<string>{}</string>", escape(s));
+            }
+            PersistentDataType::Char(c) => {
+                let _ = write!(out, "<char>{}</char>", escape(&c.to_string()));
+            }
+            PersistentDataType::I32(v) => {
+                let _ = write!(out, "<i32>{v}</i32>");
+            }
+            PersistentDataType::I64(v) => {
+                let _ = write!(out, "<i64>{v}</i64>");
+            }
+            PersistentDataType::U8(v) => {
+                let _ = write!(out, "<u8>{v}</u8>");
+            }
+            PersistentDataType::U16(v) => {
+                let _ = write!(out, "<u16>{v}</u16>");
+            }
+            PersistentDataType::U32(v) => {
+                let _ = write!(out, "<u32>{v}</u32>");
+            }
+            PersistentDataType::U64(v) => {
+                let _ = write!(out, "<u64>{v}</u64>");
+            }
+            PersistentDataType::F32(v) => {
+                let _ = write!(out, "<f32>{v}</f32>");
+            }
+            PersistentDataType::F64(v) => {
+                let _ = write!(out, "<f64>{v}</f64>");
+            }
+            PersistentDataType::Bytes(bytes) => {
+                let _ = write!(out, "<bytes>{}</bytes>", to_hex(bytes));
+            }
+            PersistentDataType::IntArray(ints) => {
+                out.push_str("<intarray>");
+                for (i, v) in ints.iter().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    let _ = write!(out, "{v}");
+                }
+                out.push_str("</intarray>");
+            }
+            PersistentDataType::LongArray(longs) => {
+                out.push_str("<longarray>");
+                for (i, v) in longs.iter().enumerate() {
+                    if i > 0 {
+                        out.push(' ');
+                    }
+                    let _ = write!(out, "{v}");
+                }
+                out.push_str("</longarray>");
+            }
+            PersistentDataType::List(items) => {
+                out.push_str("<list>\n");
+                for item in items {
+                    indent(out, depth + 1);
+                    out.push_str("<item>");
+                    write_value(out, item, depth + 1);
+                    out.push_str("</item>\n");
+                }
+                indent(out, depth);
+                out.push_str("</list>");
+            }
+            PersistentDataType::Container(NestedContainer(nested)) => {
+                out.push_str("<container>\n");
+                write_entries(out, nested, depth + 1);
+                indent(out, depth);
+                out.push_str("</container>");
+            }
+            PersistentDataType::Compound(map) => {
+                out.push_str("<compound>\n");
+                for (key, value) in map {
+                    indent(out, depth + 1);
+                    let _ = write!(out, "<field name=\"{}\">", escape(key));
+                    write_value(out, value, depth + 1);
+                    out.push_str("</field>\n");
+                }
+                indent(out, depth);
+                out.push_str("</compound>");
+            }
+        }
+    }
+
+    fn escape(value: &str) -> String {
+        value
+            .replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+            .replace('"', "&quot;")
+    }
+
+    fn unescape(value: &str) -> String {
+        value
+            .replace("&quot;", "\"")
+            .replace("&gt;", ">")
+            .replace("&lt;", "<")
+            .replace("&amp;", "&")
+    }
+
+    fn to_hex(bytes: &[u8]) -> String {
+        let mut out = String::with_capacity(bytes.len() * 2);
+        for byte in bytes {
+            let _ = write!(out, "{byte:02x}");
+        }
+        out
+    }
+
+    fn from_hex(text: &str) -> Result<Vec<u8>, DocumentError> {
+        let text = text.trim();
+        if text.len() % 2 != 0 {
+            return Err(DocumentError::Xml("odd-length hex in <bytes>".to_string()));
+        }
+        (0..text.len())
+            .step_by(2)
+            .map(|i| {
+                u8::from_str_radix(&text[i..i + 2], 16)
+                    .map_err(|error| DocumentError::Xml(error.to_string()))
+            })
+            .collect()
+    }
+
+    pub(super) fn read(text: &str) -> Result<PersistentDataContainer, DocumentError> {
+        let mut parser = Parser::new(text);
+        parser.expect_open("pdc")?;
+        let container = read_entries(&mut parser, "pdc")?;
+        Ok(container)
+    }
+
+    /// Reads `<entry>` children until the parent's closing tag.
+    fn read_entries(parser: &mut Parser, parent: &str) -> Result<PersistentDataContainer, DocumentError> {
+        let container = PersistentDataContainer::new();
+        loop {
+            match parser.next_tag()? {
+                Tag::Close(name) if name == parent => break,
+                Tag::Open { name, attrs } if name == "entry" => {
+                    let key = attrs
+                        .ok_or_else(|| DocumentError::Xml("<entry> missing key".to_string()))?;
+                    let value = read_value(parser)?;
+                    parser.expect_close("entry")?;
+                    container.insert(parse_key(&unescape(&key)), value);
+                }
+                other => {
+                    return Err(DocumentError::Xml(format!(
+                        "unexpected {other:?} inside <{parent}>"
+                    )));
+                }
+            }
+        }
+        Ok(container)
+    }
+
+    fn read_value(parser: &mut Parser) -> Result<PersistentDataType, DocumentError> {
+        let (name, _) = match parser.next_tag()? {
+            Tag::Open { name, attrs } => (name, attrs),
+            other => {
+                return Err(DocumentError::Xml(format!("expected a value, found {other:?}")));
+            }
+        };
+
+        let value = match name.as_str() {
+            "list" => {
+                let mut items = Vec::new();
+                loop {
+                    match parser.next_tag()? {
+                        Tag::Close(close) if close == "list" => break,
+                        Tag::Open { name, .. } if name == "item" => {
+                            items.push(read_value(parser)?);
+                            parser.expect_close("item")?;
+                        }
+                        other => {
+                            return Err(DocumentError::Xml(format!(
+                                "unexpected {other:?} inside <list>"
+                            )));
+                        }
+                    }
+                }
+                return Ok(PersistentDataType::List(items));
+            }
+            "container" => {
+                let nested = read_entries(parser, "container")?;
+                return Ok(PersistentDataType::Container(NestedContainer(nested)));
+            }
+            "compound" => {
+                let mut map = std::collections::HashMap::new();
+                loop {
+                    match parser.next_tag()? {
+                        Tag::Close(close) if close == "compound" => break,
+                        Tag::Open { name, attrs } if name == "field" => {
+                            let field = attrs.ok_or_else(|| {
+                                DocumentError::Xml("<field> missing name".to_string())
+                            })?;
+                            let value = read_value(parser)?;
+                            parser.expect_close("field")?;
+                            map.insert(unescape(&field), value);
+                        }
+                        other => {
+                            return Err(DocumentError::Xml(format!(
+                                "unexpected {other:?} inside <compound>"
+                            )));
+                        }
+                    }
+                }
+                return Ok(PersistentDataType::Compound(map));
+            }
+            _ => {
+                let text = parser.text_until_close(&name)?;
+                parse_scalar(&name, &text)?
+            }
+        };
+        Ok(value)
+    }
+
+    fn parse_scalar(name: &str, text: &str) -> Result<PersistentDataType, DocumentError> {
+        let parse = |ok: PersistentDataType| Ok(ok);
+        let num_err = |error: std::num::ParseIntError| DocumentError::Xml(error.to_string());
+        let float_err = |error: std::num::ParseFloatError| DocumentError::Xml(error.to_string());
+        match name {
+            "bool" => parse(PersistentDataType::Bool(text.trim() == "true")),
+            "string" => parse(PersistentDataType::String(unescape(text))),
+            "char" => unescape(text)
+                .chars()
+                .next()
+                .map(PersistentDataType::Char)
+                .map(Ok)
+                .unwrap_or_else(|| Err(DocumentError::Xml("empty <char>".to_string()))),
+            "i32" => parse(PersistentDataType::I32(text.trim().parse().map_err(num_err)?)),
+            "i64" => parse(PersistentDataType::I64(text.trim().parse().map_err(num_err)?)),
+            "u8" => parse(PersistentDataType::U8(text.trim().parse().map_err(num_err)?)),
+            "u16" => parse(PersistentDataType::U16(text.trim().parse().map_err(num_err)?)),
+            "u32" => parse(PersistentDataType::U32(text.trim().parse().map_err(num_err)?)),
+            "u64" => parse(PersistentDataType::U64(text.trim().parse().map_err(num_err)?)),
+            "f32" => parse(PersistentDataType::F32(text.trim().parse().map_err(float_err)?)),
+            "f64" => parse(PersistentDataType::F64(text.trim().parse().map_err(float_err)?)),
+            "bytes" => parse(PersistentDataType::Bytes(from_hex(text)?.into_boxed_slice())),
+            "intarray" => {
+                let ints = text
+                    .split_whitespace()
+                    .map(|v| v.parse().map_err(num_err))
+                    .collect::<Result<Vec<i32>, _>>()?;
+                parse(PersistentDataType::IntArray(ints))
+            }
+            "longarray" => {
+                let longs = text
+                    .split_whitespace()
+                    .map(|v| v.parse().map_err(num_err))
+                    .collect::<Result<Vec<i64>, _>>()?;
+                parse(PersistentDataType::LongArray(longs))
+            }
+            other => Err(DocumentError::Xml(format!("unknown value tag <{other}>"))),
+        }
+    }
+
+    /// A parsed tag produced by the [`Parser`].
+    #[derive(Debug)]
+    enum Tag {
+        Open { name: String, attrs: Option<String> },
+        Close(String),
+    }
+
+    /// A minimal recursive-descent scanner over the exact XML that [`write`]
+    /// emits: element tags with at most one `key="..."` or `name="..."` attribute and text
+    /// content. It is deliberately small rather than a general XML parser.
+    struct Parser<'a> {
+        rest: &'a str,
+    }
+
+    impl<'a> Parser<'a> {
+        fn new(text: &'a str) -> Self {
+            Self { rest: text }
+        }
+
+        /// Consumes the next `<...>` tag, skipping leading text/whitespace.
+        fn next_tag(&mut self) -> Result<Tag, DocumentError> {
+            let start = self
+                .rest
+                .find('<')
+                .ok_or_else(|| DocumentError::Xml("expected a tag".to_string()))?;
+            let end = self.rest[start..]
+                .find('>')
+                .ok_or_else(|| DocumentError::Xml("unterminated tag".to_string()))?
+                + start;
+            let inner = self.rest[start + 1..end].trim();
+            self.rest = &self.rest[end + 1..];
+
+            if let Some(name) = inner.strip_prefix('/') {
+                return Ok(Tag::Close(name.trim().to_string()));
+            }
+            match inner.split_once(char::is_whitespace) {
+                Some((name, attrs)) => {
+                    // Tags carry at most one attribute (`key="..."` or
+                    // `name="..."`); capture its quoted value regardless of name.
+                    let value = attrs
+                        .split_once('"')
+                        .and_then(|(_, rest)| rest.strip_suffix('"'))
+                        .map(str::to_string);
+                    Ok(Tag::Open {
+                        name: name.to_string(),
+                        attrs: value,
+                    })
+                }
+                None => Ok(Tag::Open {
+                    name: inner.to_string(),
+                    attrs: None,
+                }),
+            }
+        }
+
+        fn expect_open(&mut self, name: &str) -> Result<(), DocumentError> {
+            match self.next_tag()? {
+                Tag::Open { name: got, .. } if got == name => Ok(()),
+                other => Err(DocumentError::Xml(format!("expected <{name}>, found {other:?}"))),
+            }
+        }
+
+        fn expect_close(&mut self, name: &str) -> Result<(), DocumentError> {
+            match self.next_tag()? {
+                Tag::Close(got) if got == name => Ok(()),
+                other => Err(DocumentError::Xml(format!("expected </{name}>, found {other:?}"))),
+            }
+        }
+
+        /// Returns the raw text up to `</name>` and consumes the closing tag.
+        fn text_until_close(&mut self, name: &str) -> Result<String, DocumentError> {
+            let close = format!("</{name}>");
+            let end = self
+                .rest
+                .find(&close)
+                .ok_or_else(|| DocumentError::Xml(format!("missing </{name}>")))?;
+            let text = self.rest[..end].to_string();
+            self.rest = &self.rest[end + close.len()..];
+            Ok(text)
+        }
+    }
+}
+
+/// Parses a `namespace:key` string into a key, matching the nested-key parsing
+/// used elsewhere in the persistence layer.
+fn parse_key(raw: &str) -> NamespacedKey {
+    match raw.split_once(':') {
+        Some((namespace, key)) => NamespacedKey::from_parts(namespace, key),
+        None => NamespacedKey::from_parts("", raw),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn key(key: &str) -> NamespacedKey {
+        NamespacedKey::new("test", key).unwrap()
+    }
+
+    /// A container exercising scalars, `Bytes`, and nested `List`/`Compound`.
+    fn sample() -> PersistentDataContainer {
+        let container = PersistentDataContainer::new();
+        container.insert(key("flag"), PersistentDataType::Bool(true));
+        container.insert(key("glyph"), PersistentDataType::Char('ß'));
+        container.insert(key("byte"), PersistentDataType::U8(200));
+        container.insert(key("blob"), PersistentDataType::Bytes(vec![0, 1, 2, 255].into_boxed_slice()));
+
+        let mut field = HashMap::new();
+        field.insert("price".to_string(), PersistentDataType::I64(1 << 40));
+        container.insert(
+            key("items"),
+            PersistentDataType::List(vec![
+                PersistentDataType::Compound(field),
+                PersistentDataType::String("sword".to_string()),
+            ]),
+        );
+
+        let nested = PersistentDataContainer::new();
+        nested.insert(key("count"), PersistentDataType::U32(3));
+        container.insert(key("bag"), PersistentDataType::Container(NestedContainer(nested)));
+        container
+    }
+
+    fn assert_same(a: &PersistentDataContainer, b: &PersistentDataContainer) {
+        assert_eq!(
+            NestedContainer(a.clone()),
+            NestedContainer(b.clone()),
+            "containers differ after round-trip"
+        );
+    }
+
+    #[test]
+    fn xml_round_trips_losslessly() {
+        let original = sample();
+        let bytes = export(&original, Format::Xml);
+        let restored = import(Format::Xml, &bytes).unwrap();
+        assert_same(&original, &restored);
+    }
+
+    #[test]
+    fn binary_round_trips_losslessly() {
+        let original = sample();
+        let bytes = export(&original, Format::Binary);
+        let restored = import(Format::Binary, &bytes).unwrap();
+        assert_same(&original, &restored);
+    }
+
+    #[test]
+    fn empty_container_round_trips_in_both_formats() {
+        for format in [Format::Xml, Format::Binary] {
+            let original = PersistentDataContainer::new();
+            let bytes = export(&original, format);
+            let restored = import(format, &bytes).unwrap();
+            assert_eq!(restored.len(), 0);
+        }
+    }
+
+    #[test]
+    fn empty_nested_list_and_compound_round_trip() {
+        let container = PersistentDataContainer::new();
+        container.insert(key("list"), PersistentDataType::List(Vec::new()));
+        container.insert(key("map"), PersistentDataType::Compound(HashMap::new()));
+        for format in [Format::Xml, Format::Binary] {
+            let restored = import(format, &export(&container, format)).unwrap();
+            assert_same(&container, &restored);
+        }
+    }
+
+    #[test]
+    fn truncated_binary_is_an_error_not_a_panic() {
+        let bytes = export(&sample(), Format::Binary);
+        assert!(import(Format::Binary, &bytes[..bytes.len() - 3]).is_err());
+    }
+}
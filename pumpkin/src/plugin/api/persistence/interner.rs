@@ -0,0 +1,109 @@
+//! A global string interner for [`NamespacedKey`] components.
+//!
+//! Namespaces (`"minecraft"`, plugin crate names) and keys are heavily
+//! duplicated across the thousands of containers held by entities and item
+//! stacks. Interning collapses the duplicate storage to a single owned copy and
+//! turns key comparisons into integer compares: a [`NamespacedKey`] keeps two
+//! [`Symbol`]s instead of two owned `String`s.
+//!
+//! [`NamespacedKey`]: crate::plugin::persistence::NamespacedKey
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// The default size of a freshly allocated arena chunk, in bytes. Strings larger
+/// than this get a dedicated chunk of their own.
+const CHUNK_SIZE: usize = 4096;
+
+/// A compact handle to an interned string. Cheap to copy, hash, and compare;
+/// resolve it back to its text with [`Symbol::resolve`].
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Interns `value`, returning the existing symbol if it has been seen before
+    /// or allocating a new one otherwise.
+    #[must_use]
+    pub fn intern(value: &str) -> Self {
+        interner().lock().unwrap().intern(value)
+    }
+
+    /// Resolves this symbol back to its interned text.
+    #[must_use]
+    pub fn resolve(self) -> &'static str {
+        interner().lock().unwrap().resolve(self)
+    }
+}
+
+/// A bump allocator that owns interned string bytes for the life of the process.
+///
+/// Chunks are boxed slices that are never moved or freed, so the `&'static str`s
+/// handed out stay valid for as long as the arena lives (which, being stored in
+/// a process-lifetime global, is forever).
+#[derive(Default)]
+struct DroplessArena {
+    /// All allocated chunks; the last one is the current bump target.
+    chunks: Vec<Box<[u8]>>,
+    /// Bytes already used in the current (last) chunk.
+    used: usize,
+}
+
+impl DroplessArena {
+    /// Copies `value` into the arena and returns a `'static` reference to it.
+    fn alloc_str(&mut self, value: &str) -> &'static str {
+        let len = value.len();
+        let needs_chunk = self
+            .chunks
+            .last()
+            .is_none_or(|chunk| chunk.len() - self.used < len);
+        if needs_chunk {
+            self.chunks.push(vec![0u8; len.max(CHUNK_SIZE)].into_boxed_slice());
+            self.used = 0;
+        }
+
+        let chunk = self.chunks.last_mut().unwrap();
+        let start = self.used;
+        chunk[start..start + len].copy_from_slice(value.as_bytes());
+        self.used += len;
+
+        let ptr = chunk[start..].as_ptr();
+        // SAFETY: the boxed slice backing `ptr` is owned by `self.chunks` and is
+        // never moved or freed (the arena lives in a process-lifetime global),
+        // so the bytes outlive the returned reference. They are valid UTF-8
+        // because they were just copied from `value`.
+        unsafe { std::str::from_utf8_unchecked(std::slice::from_raw_parts(ptr, len)) }
+    }
+}
+
+/// The interner proper: an arena owning the bytes, plus both directions of the
+/// value ↔ id mapping.
+#[derive(Default)]
+struct Interner {
+    arena: DroplessArena,
+    /// Value → symbol, for deduplicating repeated interns.
+    lookup: HashMap<&'static str, Symbol>,
+    /// Symbol → value, indexed by the symbol's id.
+    reverse: Vec<&'static str>,
+}
+
+impl Interner {
+    fn intern(&mut self, value: &str) -> Symbol {
+        if let Some(symbol) = self.lookup.get(value) {
+            return *symbol;
+        }
+        let stored = self.arena.alloc_str(value);
+        let symbol = Symbol(self.reverse.len() as u32);
+        self.reverse.push(stored);
+        self.lookup.insert(stored, symbol);
+        symbol
+    }
+
+    fn resolve(&self, symbol: Symbol) -> &'static str {
+        self.reverse[symbol.0 as usize]
+    }
+}
+
+fn interner() -> &'static Mutex<Interner> {
+    static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(|| Mutex::new(Interner::default()))
+}
@@ -0,0 +1,174 @@
+//! Schema versioning and migration for persisted containers.
+//!
+//! Each serialized container carries a schema-version header. On load the
+//! stored version is read and a chain of migrations is applied until the data
+//! reaches [`CURRENT_SCHEMA_VERSION`], at which point it is safe to deserialize
+//! into the current [`PersistentDataType`]. Data written by a newer server than
+//! the one loading it is rejected with a typed error rather than silently
+//! dropped.
+//!
+//! [`PersistentDataType`]: crate::plugin::persistence::PersistentDataType
+
+use std::collections::HashMap;
+
+use pumpkin_nbt::compound::NbtCompound;
+use pumpkin_nbt::tag::NbtTag;
+
+/// The schema version the running server writes. Bump this whenever the shape of
+/// the persisted data changes and register a migration from the previous
+/// version.
+pub const CURRENT_SCHEMA_VERSION: i32 = 1;
+
+/// The reserved root key the schema version is stored under, beside the
+/// per-namespace compounds. It holds an `Int`, and `to_pdc` reads only compound
+/// children of the root, so it is not read back as plugin data.
+pub const SCHEMA_VERSION_KEY: &str = "__schema_version";
+
+/// A transform that upgrades raw on-disk data from one schema version to the
+/// next.
+pub type Migration = fn(NbtCompound) -> NbtCompound;
+
+/// Error returned when stored data cannot be migrated to the current schema.
+#[derive(Debug)]
+pub enum MigrationError {
+    /// The data was written by a newer server than this one.
+    NewerThanSupported { found: i32, current: i32 },
+    /// No migration is registered for a version in the chain.
+    MissingMigration(i32),
+}
+
+impl std::fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NewerThanSupported { found, current } => write!(
+                f,
+                "persisted schema version {found} is newer than the supported version {current}"
+            ),
+            Self::MissingMigration(version) => {
+                write!(f, "no migration registered from schema version {version}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+/// Reads the schema version stored in a root compound, defaulting to `0` for
+/// data written before versioning existed.
+#[must_use]
+pub fn read_version(raw: &NbtCompound) -> i32 {
+    match raw.child_tags.get(SCHEMA_VERSION_KEY) {
+        Some(NbtTag::Int(version)) => *version,
+        _ => 0,
+    }
+}
+
+/// Stamps the current schema version into a root compound prior to writing.
+pub fn stamp_version(raw: &mut NbtCompound) {
+    raw.put(SCHEMA_VERSION_KEY, NbtTag::Int(CURRENT_SCHEMA_VERSION));
+}
+
+/// A registry of migrations keyed by their source version, applied in sequence.
+#[derive(Default)]
+pub struct MigrationRegistry {
+    /// `migrations[v]` upgrades data from version `v` to version `v + 1`.
+    migrations: HashMap<i32, Migration>,
+}
+
+impl MigrationRegistry {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the migration that upgrades `from_version` to `from_version + 1`.
+    pub fn register(&mut self, from_version: i32, migration: Migration) {
+        self.migrations.insert(from_version, migration);
+    }
+
+    /// Runs the migration chain on `raw`, whose current version is `version`,
+    /// until it reaches [`CURRENT_SCHEMA_VERSION`].
+    pub fn migrate(&self, mut version: i32, mut raw: NbtCompound) -> Result<NbtCompound, MigrationError> {
+        if version > CURRENT_SCHEMA_VERSION {
+            return Err(MigrationError::NewerThanSupported {
+                found: version,
+                current: CURRENT_SCHEMA_VERSION,
+            });
+        }
+        while version < CURRENT_SCHEMA_VERSION {
+            let migration = self
+                .migrations
+                .get(&version)
+                .ok_or(MigrationError::MissingMigration(version))?;
+            raw = migration(raw);
+            version += 1;
+        }
+        Ok(raw)
+    }
+
+    /// Reads the stored version from `raw` and migrates it to the current
+    /// schema, returning the upgraded compound ready to deserialize.
+    pub fn upgrade(&self, raw: NbtCompound) -> Result<NbtCompound, MigrationError> {
+        let version = read_version(&raw);
+        self.migrate(version, raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unversioned() -> NbtCompound {
+        NbtCompound::new()
+    }
+
+    #[test]
+    fn unversioned_data_reads_as_version_zero() {
+        assert_eq!(read_version(&unversioned()), 0);
+    }
+
+    #[test]
+    fn stamp_is_read_back() {
+        let mut compound = NbtCompound::new();
+        stamp_version(&mut compound);
+        assert_eq!(read_version(&compound), CURRENT_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn migration_chain_runs_until_current() {
+        fn mark(mut raw: NbtCompound) -> NbtCompound {
+            raw.put("migrated", NbtTag::Byte(1));
+            raw
+        }
+        let mut registry = MigrationRegistry::new();
+        registry.register(0, mark);
+
+        // CURRENT_SCHEMA_VERSION is 1, so the single 0 -> 1 migration applies.
+        let upgraded = registry.upgrade(unversioned()).unwrap();
+        assert!(matches!(
+            upgraded.child_tags.get("migrated"),
+            Some(NbtTag::Byte(1))
+        ));
+    }
+
+    #[test]
+    fn missing_migration_is_reported() {
+        let registry = MigrationRegistry::new();
+        match registry.migrate(0, unversioned()) {
+            Err(MigrationError::MissingMigration(0)) => {}
+            other => panic!("expected MissingMigration(0), got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn newer_than_supported_is_rejected() {
+        let registry = MigrationRegistry::new();
+        match registry.migrate(CURRENT_SCHEMA_VERSION + 1, unversioned()) {
+            Err(MigrationError::NewerThanSupported { found, current }) => {
+                assert_eq!(found, CURRENT_SCHEMA_VERSION + 1);
+                assert_eq!(current, CURRENT_SCHEMA_VERSION);
+            }
+            other => panic!("expected NewerThanSupported, got {other:?}"),
+        }
+    }
+}
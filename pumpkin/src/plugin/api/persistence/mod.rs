@@ -1,16 +1,32 @@
+pub mod document;
+pub mod interner;
+pub mod migration;
 pub mod nbt;
+pub mod query;
+pub mod storage;
+
+use std::collections::HashMap;
+
+use interner::Symbol;
+use storage::StorageBackend;
 
 use dashmap::DashMap;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Represents a key with an associated namespace.
 ///
 /// This struct is used to differentiate persistent data by plugin through namespacing.
+///
+/// The namespace and key are stored as interned [`Symbol`]s rather than owned
+/// `String`s, so duplicate namespaces across the many containers held by
+/// entities and item stacks share one copy and key comparisons are integer
+/// compares. The `namespace:key` text is resolved back out on `Display` and
+/// serialization, so the on-disk format is unchanged.
 #[allow(dead_code)]
-#[derive(Eq, Hash, PartialEq, Clone, Debug, Serialize, Deserialize)]
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
 pub struct NamespacedKey {
-    pub(crate) namespace: String,
-    pub(crate) key: String,
+    pub(crate) namespace: Symbol,
+    pub(crate) key: Symbol,
 }
 
 #[derive(Debug)]
@@ -46,15 +62,55 @@ impl NamespacedKey {
         }
 
         Ok(Self {
-            namespace: namespace.to_ascii_lowercase(),
-            key: key.to_ascii_lowercase(),
+            namespace: Symbol::intern(&namespace.to_ascii_lowercase()),
+            key: Symbol::intern(&key.to_ascii_lowercase()),
         })
     }
+
+    /// Interns a namespace/key pair that is already known to be well-formed
+    /// (e.g. one produced by [`Display`] and read back in). Unlike [`new`], this
+    /// does no ASCII validation or case folding.
+    ///
+    /// [`new`]: Self::new
+    pub(crate) fn from_parts(namespace: &str, key: &str) -> Self {
+        Self {
+            namespace: Symbol::intern(namespace),
+            key: Symbol::intern(key),
+        }
+    }
+
+    /// Resolves the interned namespace back to its text.
+    #[must_use]
+    pub fn namespace(&self) -> &'static str {
+        self.namespace.resolve()
+    }
+
+    /// Resolves the interned key back to its text.
+    #[must_use]
+    pub fn key(&self) -> &'static str {
+        self.key.resolve()
+    }
 }
 
 impl std::fmt::Display for NamespacedKey {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}:{}", self.namespace, self.key)
+        write!(f, "{}:{}", self.namespace(), self.key())
+    }
+}
+
+impl Serialize for NamespacedKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for NamespacedKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        Ok(match raw.split_once(':') {
+            Some((namespace, key)) => Self::from_parts(namespace, key),
+            None => Self::from_parts("", &raw),
+        })
     }
 }
 
@@ -83,6 +139,28 @@ macro_rules! ns_key {
 /// Instead, the methods from the `PersistentDataHolder` trait should be used.
 pub(crate) type PersistentDataContainer = DashMap<NamespacedKey, PersistentDataType>;
 
+/// A nested [`PersistentDataContainer`] that can live inside a
+/// [`PersistentDataType`].
+///
+/// `DashMap` does not implement `PartialEq`, so this newtype provides one by
+/// comparing entries, letting `PersistentDataType` keep its `PartialEq` derive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NestedContainer(pub PersistentDataContainer);
+
+impl PartialEq for NestedContainer {
+    fn eq(&self, other: &Self) -> bool {
+        if self.0.len() != other.0.len() {
+            return false;
+        }
+        self.0.iter().all(|entry| {
+            other
+                .0
+                .get(entry.key())
+                .is_some_and(|v| *v == *entry.value())
+        })
+    }
+}
+
 /// Enum representing all allowed data types that can be stored in a `PersistentDataContainer`.
 #[allow(dead_code)]
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -99,7 +177,18 @@ pub enum PersistentDataType {
     F32(f32),
     F64(f64),
     Bytes(Box<[u8]>),
+    IntArray(Vec<i32>),
+    LongArray(Vec<i64>),
     List(Vec<PersistentDataType>),
+    /// A nested container, mapped to/from an NBT `TAG_Compound`.
+    Container(NestedContainer),
+    /// A keyed map of structured data, mirroring NBT's `TAG_Compound`. Unlike
+    /// [`Container`], whose keys are namespaced, a `Compound` is a plain
+    /// `String`-keyed tree, so plugins can model object graphs without flattening
+    /// them into many top-level keys.
+    ///
+    /// [`Container`]: PersistentDataType::Container
+    Compound(HashMap<String, PersistentDataType>),
 }
 
 /// Trait defining common operations for structs that hold a `PersistentDataContainer`.
@@ -123,6 +212,163 @@ pub trait PersistentDataHolder {
     fn iter(&self) -> Box<dyn Iterator<Item = (NamespacedKey, PersistentDataType)> + '_>;
     /// Returns a mutable reference of the container
     fn container_mut(&mut self) -> &mut PersistentDataContainer;
+
+    /// Persists this holder's data through `backend` under `holder_id`.
+    ///
+    /// The default implementation snapshots the whole container; backends that
+    /// track dirty keys (see `storage::DirtyKeys`) can persist incrementally.
+    fn flush(&self, backend: &dyn StorageBackend, holder_id: &str) {
+        let snapshot = PersistentDataContainer::new();
+        for (key, value) in self.iter() {
+            snapshot.insert(key, value);
+        }
+        backend.save(holder_id, &snapshot);
+    }
+
+    /// Replaces this holder's data with the copy stored by `backend` under
+    /// `holder_id`, so data survives restarts.
+    fn reload(&self, backend: &dyn StorageBackend, holder_id: &str) {
+        let loaded = backend.load(holder_id);
+        self.clear();
+        for entry in loaded.iter() {
+            self.insert(entry.key(), entry.value().clone());
+        }
+    }
+
+    /// Exports this holder's data as a typed document in the given `format`, for
+    /// inspection, diffing, or hand-editing (see the `document` submodule).
+    fn export(&self, format: document::Format) -> Vec<u8> {
+        let snapshot = PersistentDataContainer::new();
+        for (key, value) in self.iter() {
+            snapshot.insert(key, value);
+        }
+        document::export(&snapshot, format)
+    }
+
+    /// Replaces this holder's data with a document previously produced by
+    /// [`export`](Self::export) in the same `format`.
+    fn import(&self, format: document::Format, data: &[u8]) -> Result<(), document::DocumentError> {
+        let loaded = document::import(format, data)?;
+        self.clear();
+        for entry in loaded.iter() {
+            self.insert(entry.key(), entry.value().clone());
+        }
+        Ok(())
+    }
+
+    /// Reads a value nested under `root` by a dotted `path` (e.g.
+    /// `"shop.items.0.price"`), descending through [`Compound`] keys and [`List`]
+    /// indices. Returns the value at `root` itself when `path` is empty.
+    ///
+    /// [`Compound`]: PersistentDataType::Compound
+    /// [`List`]: PersistentDataType::List
+    fn get_path(&self, root: &NamespacedKey, path: &str) -> Option<PersistentDataType> {
+        let value = self.get(root)?;
+        let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+        if segments.is_empty() {
+            return Some(value);
+        }
+        path_get(&value, &segments)
+    }
+
+    /// Inserts `value` at the dotted `path` nested under `root`, creating
+    /// intermediate [`Compound`]s as needed. Numeric segments index into an
+    /// existing [`List`] (a final index past its end appends); every other
+    /// segment is a `Compound` key, and a `List` met by a segment that is not a
+    /// usable index is replaced by a `Compound`.
+    ///
+    /// [`Compound`]: PersistentDataType::Compound
+    /// [`List`]: PersistentDataType::List
+    fn insert_path(&self, root: &NamespacedKey, path: &str, value: PersistentDataType) {
+        let segments: Vec<&str> = path.split('.').filter(|s| !s.is_empty()).collect();
+        if segments.is_empty() {
+            self.insert(root, value);
+            return;
+        }
+        let mut root_value = self
+            .get(root)
+            .unwrap_or_else(|| PersistentDataType::Compound(HashMap::new()));
+        path_insert(&mut root_value, &segments, value);
+        self.insert(root, root_value);
+    }
+}
+
+/// Reads the value reached by following `segments` through nested `Compound`
+/// keys and `List` indices.
+fn path_get(value: &PersistentDataType, segments: &[&str]) -> Option<PersistentDataType> {
+    let (head, tail) = segments.split_first()?;
+    let next = match value {
+        PersistentDataType::Compound(map) => map.get(*head)?,
+        PersistentDataType::List(items) => items.get(head.parse::<usize>().ok()?)?,
+        _ => return None,
+    };
+    if tail.is_empty() {
+        Some(next.clone())
+    } else {
+        path_get(next, tail)
+    }
+}
+
+/// Writes `new` at the location named by `segments`, creating intermediate
+/// `Compound`s along the way. A scalar in the way is replaced by a `Compound`,
+/// and so is a `List` whose segment is not a usable index; a final numeric
+/// segment past the end of a `List` appends to it.
+fn path_insert(value: &mut PersistentDataType, segments: &[&str], new: PersistentDataType) {
+    let Some((head, tail)) = segments.split_first() else {
+        return;
+    };
+
+    if tail.is_empty() {
+        match value {
+            PersistentDataType::List(items) => {
+                if let Ok(index) = head.parse::<usize>() {
+                    if index < items.len() {
+                        items[index] = new;
+                    } else {
+                        items.push(new);
+                    }
+                    return;
+                }
+                *value = single_field(head, new);
+            }
+            PersistentDataType::Compound(map) => {
+                map.insert((*head).to_string(), new);
+            }
+            _ => *value = single_field(head, new),
+        }
+        return;
+    }
+
+    match value {
+        PersistentDataType::List(items) => {
+            if let Some(child) = head.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                path_insert(child, tail, new);
+            } else {
+                *value = single_field(head, descend(tail, new));
+            }
+        }
+        PersistentDataType::Compound(map) => {
+            let child = map
+                .entry((*head).to_string())
+                .or_insert_with(|| PersistentDataType::Compound(HashMap::new()));
+            path_insert(child, tail, new);
+        }
+        _ => *value = single_field(head, descend(tail, new)),
+    }
+}
+
+/// A fresh `Compound` holding a single `key` → `value` pair.
+fn single_field(key: &str, value: PersistentDataType) -> PersistentDataType {
+    let mut map = HashMap::new();
+    map.insert(key.to_string(), value);
+    PersistentDataType::Compound(map)
+}
+
+/// Builds the nested `Compound` chain needed to place `new` at `segments`.
+fn descend(segments: &[&str], new: PersistentDataType) -> PersistentDataType {
+    let mut value = PersistentDataType::Compound(HashMap::new());
+    path_insert(&mut value, segments, new);
+    value
 }
 
 /// Trait to extract the inner value from a `PersistentDataType`.
@@ -182,4 +428,55 @@ from_persistent!(F64, f64);
 // Clone types
 from_persistent!(clone String, String);
 from_persistent!(clone Bytes, Box<[u8]>);
+from_persistent!(clone IntArray, Vec<i32>);
+from_persistent!(clone LongArray, Vec<i64>);
 from_persistent!(clone List, Vec<PersistentDataType>);
+from_persistent!(clone Container, NestedContainer);
+from_persistent!(clone Compound, HashMap<String, PersistentDataType>);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nbt::{NbtCompoundExt, from_pdc};
+
+    fn key(key: &str) -> NamespacedKey {
+        NamespacedKey::new("test", key).unwrap()
+    }
+
+    /// Builds a `shop { items: [ { price } ] }` tree for path tests.
+    fn shop() -> PersistentDataType {
+        let mut item = HashMap::new();
+        item.insert("price".to_string(), PersistentDataType::I32(42));
+        let mut root = HashMap::new();
+        root.insert(
+            "items".to_string(),
+            PersistentDataType::List(vec![PersistentDataType::Compound(item)]),
+        );
+        PersistentDataType::Compound(root)
+    }
+
+    #[test]
+    fn dotted_path_resolves_before_persistence() {
+        let value = shop();
+        assert_eq!(
+            path_get(&value, &["items", "0", "price"]),
+            Some(PersistentDataType::I32(42))
+        );
+    }
+
+    #[test]
+    fn dotted_path_still_resolves_after_save_load_cycle() {
+        let container = PersistentDataContainer::new();
+        container.insert(key("shop"), shop());
+
+        let reloaded = from_pdc(&container).to_pdc();
+        let value = reloaded.get(&key("shop")).unwrap().value().clone();
+
+        // The Compound must not have reloaded as a Container, or these segments
+        // would not be found.
+        assert_eq!(
+            path_get(&value, &["items", "0", "price"]),
+            Some(PersistentDataType::I32(42))
+        );
+    }
+}
@@ -1,7 +1,38 @@
-use crate::plugin::persistence::{NamespacedKey, PersistentDataContainer, PersistentDataType};
+use crate::plugin::persistence::{
+    NamespacedKey, NestedContainer, PersistentDataContainer, PersistentDataType,
+};
 use pumpkin_nbt::compound::NbtCompound;
 use pumpkin_nbt::tag::NbtTag;
 
+/// Key of the type marker inside a value's wrapper compound.
+const TYPE_KEY: &str = "t";
+/// Key of the payload inside a value's wrapper compound.
+const VALUE_KEY: &str = "v";
+
+// Stable type markers. NBT collapses several of our variants onto the same tag
+// (`Bool`/`U8` both become `Byte`, `Char`/`String` both become `String`,
+// `Container`/`Compound` both become `TAG_Compound`, …), so every value is
+// wrapped in a two-field compound `{ t: <marker>, v: <payload> }`. The marker
+// records the exact `PersistentDataType` variant, which makes the round-trip
+// lossless and removes the need to guess the variant back from the tag shape.
+const TYPE_BOOL: i8 = 0;
+const TYPE_STRING: i8 = 1;
+const TYPE_CHAR: i8 = 2;
+const TYPE_I32: i8 = 3;
+const TYPE_I64: i8 = 4;
+const TYPE_U8: i8 = 5;
+const TYPE_U16: i8 = 6;
+const TYPE_U32: i8 = 7;
+const TYPE_U64: i8 = 8;
+const TYPE_F32: i8 = 9;
+const TYPE_F64: i8 = 10;
+const TYPE_BYTES: i8 = 11;
+const TYPE_INT_ARRAY: i8 = 12;
+const TYPE_LONG_ARRAY: i8 = 13;
+const TYPE_LIST: i8 = 14;
+const TYPE_CONTAINER: i8 = 15;
+const TYPE_COMPOUND: i8 = 16;
+
 pub trait NbtCompoundExt {
     fn to_pdc(&self) -> PersistentDataContainer;
 }
@@ -14,29 +45,9 @@ impl NbtCompoundExt for NbtCompound {
             if let NbtTag::Compound(ns_compound) = ns_tag {
                 for (key, tag) in &ns_compound.child_tags {
                     if let Ok(ns_key) = NamespacedKey::new(namespace, key) {
-                        let value = match tag {
-                            NbtTag::Byte(b) => PersistentDataType::Bool(*b != 0),
-                            NbtTag::Short(s) => PersistentDataType::I32(i32::from(*s)),
-                            NbtTag::Int(i) => PersistentDataType::I32(*i),
-                            NbtTag::Long(l) => PersistentDataType::I64(*l),
-                            NbtTag::Float(f) => PersistentDataType::F32(*f),
-                            NbtTag::Double(d) => PersistentDataType::F64(*d),
-                            NbtTag::String(s) => PersistentDataType::String(s.clone()),
-                            NbtTag::ByteArray(bytes) => PersistentDataType::Bytes(bytes.clone()),
-                            NbtTag::List(list) => PersistentDataType::List(
-                                list.iter()
-                                    .filter_map(|t| match t {
-                                        NbtTag::Int(i) => Some(PersistentDataType::I32(*i)),
-                                        NbtTag::String(s) => {
-                                            Some(PersistentDataType::String(s.clone()))
-                                        }
-                                        _ => None,
-                                    })
-                                    .collect(),
-                            ),
-                            _ => continue, // Unsupported tag
-                        };
-                        container.insert(ns_key, value);
+                        if let Some(value) = tag_to_pdt(tag) {
+                            container.insert(ns_key, value);
+                        }
                     }
                 }
             }
@@ -45,6 +56,93 @@ impl NbtCompoundExt for NbtCompound {
     }
 }
 
+/// Wraps a payload tag in a `{ t: <marker>, v: <payload> }` compound so the
+/// exact `PersistentDataType` variant survives the round-trip.
+fn wrap(marker: i8, payload: NbtTag) -> NbtTag {
+    let mut compound = NbtCompound::new();
+    compound.put(TYPE_KEY, NbtTag::Byte(marker));
+    compound.put(VALUE_KEY, payload);
+    NbtTag::Compound(compound)
+}
+
+/// Recursively converts a single wrapped NBT tag back into its
+/// `PersistentDataType`. Returns `None` for anything not produced by
+/// [`pdt_to_tag`] (a bare tag, or an unknown marker).
+fn tag_to_pdt(tag: &NbtTag) -> Option<PersistentDataType> {
+    let NbtTag::Compound(wrapper) = tag else {
+        return None;
+    };
+    let marker = match wrapper.child_tags.get(TYPE_KEY) {
+        Some(NbtTag::Byte(marker)) => *marker,
+        _ => return None,
+    };
+    let payload = wrapper.child_tags.get(VALUE_KEY)?;
+
+    Some(match (marker, payload) {
+        (TYPE_BOOL, NbtTag::Byte(b)) => PersistentDataType::Bool(*b != 0),
+        (TYPE_STRING, NbtTag::String(s)) => PersistentDataType::String(s.clone()),
+        (TYPE_CHAR, NbtTag::String(s)) => PersistentDataType::Char(s.chars().next()?),
+        (TYPE_I32, NbtTag::Int(i)) => PersistentDataType::I32(*i),
+        (TYPE_I64, NbtTag::Long(l)) => PersistentDataType::I64(*l),
+        (TYPE_U8, NbtTag::Byte(b)) => PersistentDataType::U8(*b as u8),
+        (TYPE_U16, NbtTag::Short(s)) => PersistentDataType::U16(*s as u16),
+        (TYPE_U32, NbtTag::Int(i)) => PersistentDataType::U32(*i as u32),
+        (TYPE_U64, NbtTag::Long(l)) => PersistentDataType::U64(*l as u64),
+        (TYPE_F32, NbtTag::Float(f)) => PersistentDataType::F32(*f),
+        (TYPE_F64, NbtTag::Double(d)) => PersistentDataType::F64(*d),
+        (TYPE_BYTES, NbtTag::ByteArray(bytes)) => PersistentDataType::Bytes(bytes.clone()),
+        (TYPE_INT_ARRAY, NbtTag::IntArray(ints)) => PersistentDataType::IntArray(ints.to_vec()),
+        (TYPE_LONG_ARRAY, NbtTag::LongArray(longs)) => {
+            PersistentDataType::LongArray(longs.to_vec())
+        }
+        (TYPE_LIST, NbtTag::List(list)) => {
+            PersistentDataType::List(list.iter().filter_map(tag_to_pdt).collect())
+        }
+        (TYPE_CONTAINER, NbtTag::Compound(compound)) => {
+            PersistentDataType::Container(NestedContainer(compound_to_container(compound)))
+        }
+        (TYPE_COMPOUND, NbtTag::Compound(compound)) => {
+            PersistentDataType::Compound(compound_to_map(compound))
+        }
+        _ => return None,
+    })
+}
+
+/// Builds a plain string-keyed map from a compound, for the [`Compound`] variant.
+///
+/// [`Compound`]: PersistentDataType::Compound
+fn compound_to_map(compound: &NbtCompound) -> std::collections::HashMap<String, PersistentDataType> {
+    let mut map = std::collections::HashMap::new();
+    for (key, tag) in &compound.child_tags {
+        if let Some(value) = tag_to_pdt(tag) {
+            map.insert(key.clone(), value);
+        }
+    }
+    map
+}
+
+/// Builds a nested container from a compound, parsing each child key as a
+/// `namespace:key` pair so nested containers round-trip the same way top-level
+/// ones do.
+fn compound_to_container(compound: &NbtCompound) -> PersistentDataContainer {
+    let container = PersistentDataContainer::new();
+    for (key, tag) in &compound.child_tags {
+        if let Some(value) = tag_to_pdt(tag) {
+            container.insert(parse_nested_key(key), value);
+        }
+    }
+    container
+}
+
+/// Parses a `namespace:key` string produced by [`from_pdc`]. Keys without a
+/// colon fall back to an empty namespace.
+fn parse_nested_key(raw: &str) -> NamespacedKey {
+    match raw.split_once(':') {
+        Some((namespace, key)) => NamespacedKey::from_parts(namespace, key),
+        None => NamespacedKey::from_parts("", raw),
+    }
+}
+
 // Orphan rules suck hairy ass
 #[must_use]
 pub fn from_pdc(holder: &PersistentDataContainer) -> NbtCompound {
@@ -58,31 +156,8 @@ pub fn from_pdc(holder: &PersistentDataContainer) -> NbtCompound {
         let key = entry.key();
         let value = entry.value();
 
-        let ns_compound = namespace_map.entry(key.namespace.clone()).or_default();
-
-        let tag = match value {
-            PersistentDataType::Bool(b) => NbtTag::Byte(i8::from(*b)),
-            PersistentDataType::I32(i) => NbtTag::Int(*i),
-            PersistentDataType::I64(l) => NbtTag::Long(*l),
-            PersistentDataType::F32(f) => NbtTag::Float(*f),
-            PersistentDataType::F64(d) => NbtTag::Double(*d),
-            PersistentDataType::String(s) => NbtTag::String(s.clone()),
-            PersistentDataType::Bytes(bytes) => NbtTag::ByteArray(bytes.clone()),
-            PersistentDataType::List(list) => {
-                let nbt_list = list
-                    .iter()
-                    .map(|elem| match elem {
-                        PersistentDataType::I32(i) => NbtTag::Int(*i),
-                        PersistentDataType::String(s) => NbtTag::String(s.clone()),
-                        _ => unimplemented!(), // TODO: Add more
-                    })
-                    .collect();
-                NbtTag::List(nbt_list)
-            }
-            _ => unimplemented!(), // TODO: Add more
-        };
-
-        ns_compound.put(&key.key, tag);
+        let ns_compound = namespace_map.entry(key.namespace().to_string()).or_default();
+        ns_compound.put(key.key(), pdt_to_tag(value));
     }
 
     // Place all namespaced sub-compounds inside root compound
@@ -92,3 +167,120 @@ pub fn from_pdc(holder: &PersistentDataContainer) -> NbtCompound {
 
     compound
 }
+
+/// Recursively converts a `PersistentDataType` into its NBT equivalent, wrapped
+/// in a `{ t, v }` compound that records the source variant.
+///
+/// NBT has no distinct unsigned, `char`, or container-vs-compound tags, so the
+/// wrapper's type marker is what keeps the conversion lossless; every payload,
+/// including nested `Container`s/`Compound`s and typed `List`s, round-trips
+/// back to the exact variant it started as.
+fn pdt_to_tag(value: &PersistentDataType) -> NbtTag {
+    match value {
+        PersistentDataType::Bool(b) => wrap(TYPE_BOOL, NbtTag::Byte(i8::from(*b))),
+        PersistentDataType::Char(c) => wrap(TYPE_CHAR, NbtTag::String(c.to_string())),
+        PersistentDataType::I32(i) => wrap(TYPE_I32, NbtTag::Int(*i)),
+        PersistentDataType::I64(l) => wrap(TYPE_I64, NbtTag::Long(*l)),
+        PersistentDataType::U8(v) => wrap(TYPE_U8, NbtTag::Byte(*v as i8)),
+        PersistentDataType::U16(v) => wrap(TYPE_U16, NbtTag::Short(*v as i16)),
+        PersistentDataType::U32(v) => wrap(TYPE_U32, NbtTag::Int(*v as i32)),
+        PersistentDataType::U64(v) => wrap(TYPE_U64, NbtTag::Long(*v as i64)),
+        PersistentDataType::F32(f) => wrap(TYPE_F32, NbtTag::Float(*f)),
+        PersistentDataType::F64(d) => wrap(TYPE_F64, NbtTag::Double(*d)),
+        PersistentDataType::String(s) => wrap(TYPE_STRING, NbtTag::String(s.clone())),
+        PersistentDataType::Bytes(bytes) => wrap(TYPE_BYTES, NbtTag::ByteArray(bytes.clone())),
+        PersistentDataType::IntArray(ints) => {
+            wrap(TYPE_INT_ARRAY, NbtTag::IntArray(ints.clone().into_boxed_slice()))
+        }
+        PersistentDataType::LongArray(longs) => {
+            wrap(TYPE_LONG_ARRAY, NbtTag::LongArray(longs.clone().into_boxed_slice()))
+        }
+        PersistentDataType::List(list) => {
+            wrap(TYPE_LIST, NbtTag::List(list.iter().map(pdt_to_tag).collect()))
+        }
+        PersistentDataType::Container(NestedContainer(nested)) => {
+            let mut compound = NbtCompound::new();
+            for entry in nested {
+                compound.put(&entry.key().to_string(), pdt_to_tag(entry.value()));
+            }
+            wrap(TYPE_CONTAINER, NbtTag::Compound(compound))
+        }
+        PersistentDataType::Compound(map) => {
+            let mut compound = NbtCompound::new();
+            for (key, value) in map {
+                compound.put(key, pdt_to_tag(value));
+            }
+            wrap(TYPE_COMPOUND, NbtTag::Compound(compound))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn key(namespace: &str, key: &str) -> NamespacedKey {
+        NamespacedKey::new(namespace, key).unwrap()
+    }
+
+    fn roundtrip(value: PersistentDataType) -> PersistentDataType {
+        let container = PersistentDataContainer::new();
+        container.insert(key("test", "value"), value);
+        let restored = from_pdc(&container).to_pdc();
+        restored.get(&key("test", "value")).unwrap().clone()
+    }
+
+    #[test]
+    fn scalar_variants_survive_round_trip() {
+        for value in [
+            PersistentDataType::Bool(true),
+            PersistentDataType::Char('λ'),
+            PersistentDataType::I32(-5),
+            PersistentDataType::I64(1 << 40),
+            PersistentDataType::U8(200),
+            PersistentDataType::U16(40_000),
+            PersistentDataType::U32(3_000_000_000),
+            PersistentDataType::U64(u64::MAX),
+            PersistentDataType::F32(1.5),
+            PersistentDataType::F64(-2.25),
+            PersistentDataType::String("hi".to_string()),
+        ] {
+            assert_eq!(roundtrip(value.clone()), value);
+        }
+    }
+
+    #[test]
+    fn unsigned_and_bool_do_not_collapse() {
+        // The pre-fix codec turned `U8(5)` into `Bool(true)`; assert it no longer does.
+        assert_eq!(roundtrip(PersistentDataType::U8(5)), PersistentDataType::U8(5));
+        assert_eq!(
+            roundtrip(PersistentDataType::U16(7)),
+            PersistentDataType::U16(7)
+        );
+    }
+
+    #[test]
+    fn nested_compound_does_not_read_back_as_container() {
+        // A Compound whose keys contain a colon used to be guessed as a Container.
+        let mut map = HashMap::new();
+        map.insert("a:b".to_string(), PersistentDataType::I32(1));
+        let value = PersistentDataType::Compound(map);
+        assert_eq!(roundtrip(value.clone()), value);
+    }
+
+    #[test]
+    fn empty_compound_stays_a_compound() {
+        let value = PersistentDataType::Compound(HashMap::new());
+        assert_eq!(roundtrip(value.clone()), value);
+    }
+
+    #[test]
+    fn nested_list_and_bytes_survive() {
+        let value = PersistentDataType::List(vec![
+            PersistentDataType::Bytes(vec![1, 2, 3].into_boxed_slice()),
+            PersistentDataType::U8(9),
+        ]);
+        assert_eq!(roundtrip(value.clone()), value);
+    }
+}
@@ -0,0 +1,57 @@
+//! Query/filter helpers over a [`PersistentDataContainer`] and over the item
+//! stacks of an inventory.
+//!
+//! These give plugins a real metadata index — "find everything tagged with this
+//! key", "find values matching a predicate", "find items carrying a flag" —
+//! instead of hand-rolling iteration every time.
+
+use std::sync::Arc;
+
+use pumpkin_world::inventory::Inventory;
+use pumpkin_world::item::ItemStack;
+
+use crate::plugin::persistence::{NamespacedKey, PersistentDataContainer, PersistentDataType};
+
+/// Returns every `(key, value)` pair in `container` whose value satisfies
+/// `predicate`.
+pub fn matching<F>(
+    container: &PersistentDataContainer,
+    mut predicate: F,
+) -> Vec<(NamespacedKey, PersistentDataType)>
+where
+    F: FnMut(&NamespacedKey, &PersistentDataType) -> bool,
+{
+    container
+        .iter()
+        .filter(|entry| predicate(entry.key(), entry.value()))
+        .map(|entry| (entry.key().clone(), entry.value().clone()))
+        .collect()
+}
+
+/// Returns `true` if `container` carries `key` set to a boolean `true` flag.
+#[must_use]
+pub fn has_flag(container: &PersistentDataContainer, key: &NamespacedKey) -> bool {
+    container
+        .get(key)
+        .is_some_and(|value| matches!(*value, PersistentDataType::Bool(true)))
+}
+
+/// Returns the raw slot indices of the inventory whose item stacks satisfy
+/// `predicate`.
+///
+/// `predicate` receives each non-empty stack, so a plugin can inspect the
+/// stack's own metadata (e.g. a flag key) to decide whether it matches.
+pub async fn find_in_inventory<F>(inventory: &Arc<dyn Inventory>, mut predicate: F) -> Vec<usize>
+where
+    F: FnMut(&ItemStack) -> bool,
+{
+    let mut matches = Vec::new();
+    for slot in 0..inventory.size() {
+        let stack = inventory.get_stack(slot).await;
+        let guard = stack.lock().await;
+        if !guard.is_empty() && predicate(&guard) {
+            matches.push(slot);
+        }
+    }
+    matches
+}
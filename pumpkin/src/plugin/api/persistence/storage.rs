@@ -0,0 +1,269 @@
+//! Durable persistence for [`PersistentDataContainer`]s.
+//!
+//! The container itself lives in RAM; a [`StorageBackend`] is what gives it a
+//! home on disk (or elsewhere) so plugin data survives a restart. Backends are
+//! keyed by an opaque `holder_id` string (e.g. an entity UUID or item id).
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+
+use crate::plugin::persistence::migration::{MigrationRegistry, stamp_version};
+use crate::plugin::persistence::nbt::{NbtCompoundExt, from_pdc};
+use crate::plugin::persistence::{NamespacedKey, PersistentDataContainer};
+
+/// A pluggable place to load and save persistent data containers.
+///
+/// Implementations form a layered persistence stack: an in-memory backend for
+/// tests, a file backend for single-node servers, and a remote/object-store
+/// backend for clustered deployments.
+pub trait StorageBackend: Send + Sync {
+    /// Loads the container for `holder_id`, returning an empty container if the
+    /// holder has never been persisted.
+    fn load(&self, holder_id: &str) -> PersistentDataContainer;
+
+    /// Persists `container` for `holder_id`.
+    fn save(&self, holder_id: &str, container: &PersistentDataContainer);
+
+    /// Persists `container` off the main tick. The default implementation spawns
+    /// a blocking task that calls [`StorageBackend::save`]; backends with a
+    /// native async API may override this.
+    fn save_async<'a>(
+        self: Arc<Self>,
+        holder_id: String,
+        container: PersistentDataContainer,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send + 'a>>
+    where
+        Self: 'a,
+    {
+        Box::pin(async move {
+            let backend = self;
+            tokio::task::spawn_blocking(move || backend.save(&holder_id, &container))
+                .await
+                .ok();
+        })
+    }
+}
+
+/// In-memory backend, primarily for tests. Persists nothing across process
+/// restarts but round-trips within a single run.
+#[derive(Default)]
+pub struct InMemoryBackend {
+    holders: DashMap<String, PersistentDataContainer>,
+}
+
+impl InMemoryBackend {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StorageBackend for InMemoryBackend {
+    fn load(&self, holder_id: &str) -> PersistentDataContainer {
+        self.holders
+            .get(holder_id)
+            .map(|c| c.clone())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, holder_id: &str, container: &PersistentDataContainer) {
+        self.holders.insert(holder_id.to_string(), container.clone());
+    }
+}
+
+/// File backend that serializes each holder's container to NBT (via the `nbt`
+/// submodule) under `<dir>/<holder_id>.nbt`.
+///
+/// Every file carries a schema-version header (see the `migration` submodule);
+/// on load the stored version is migrated forward before the container is
+/// deserialized, so data written by an older server is upgraded in place.
+pub struct FileBackend {
+    dir: PathBuf,
+    migrations: MigrationRegistry,
+}
+
+impl FileBackend {
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            migrations: MigrationRegistry::new(),
+        }
+    }
+
+    /// Creates a backend with a custom set of schema migrations.
+    #[must_use]
+    pub fn with_migrations(dir: impl Into<PathBuf>, migrations: MigrationRegistry) -> Self {
+        Self {
+            dir: dir.into(),
+            migrations,
+        }
+    }
+
+    fn path_for(&self, holder_id: &str) -> PathBuf {
+        self.dir.join(format!("{holder_id}.nbt"))
+    }
+}
+
+impl StorageBackend for FileBackend {
+    fn load(&self, holder_id: &str) -> PersistentDataContainer {
+        let path = self.path_for(holder_id);
+        match std::fs::read(&path) {
+            Ok(bytes) => match pumpkin_nbt::deserializer::from_bytes(&bytes) {
+                Ok(compound) => match self.migrations.upgrade(compound) {
+                    Ok(upgraded) => upgraded.to_pdc(),
+                    Err(error) => {
+                        log::error!("Failed to migrate persistent data at {path:?}: {error}");
+                        PersistentDataContainer::new()
+                    }
+                },
+                Err(error) => {
+                    log::error!("Failed to parse persistent data at {path:?}: {error}");
+                    PersistentDataContainer::new()
+                }
+            },
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                PersistentDataContainer::new()
+            }
+            Err(error) => {
+                log::error!("Failed to read persistent data at {path:?}: {error}");
+                PersistentDataContainer::new()
+            }
+        }
+    }
+
+    fn save(&self, holder_id: &str, container: &PersistentDataContainer) {
+        if let Err(error) = std::fs::create_dir_all(&self.dir) {
+            log::error!("Failed to create persistence directory {:?}: {error}", self.dir);
+            return;
+        }
+        let path = self.path_for(holder_id);
+        let mut compound = from_pdc(container);
+        stamp_version(&mut compound);
+        let mut bytes = Vec::new();
+        pumpkin_nbt::serializer::to_bytes(&compound, &mut bytes);
+        if let Err(error) = std::fs::write(&path, bytes) {
+            log::error!("Failed to write persistent data at {path:?}: {error}");
+        }
+    }
+}
+
+/// Stub for a remote/object-store backend (S3, a database, etc.). The wiring is
+/// intentionally left to deployments that need it; the stub keeps the trait
+/// object-safe and documents the extension point.
+#[derive(Default)]
+pub struct RemoteBackend;
+
+impl StorageBackend for RemoteBackend {
+    fn load(&self, holder_id: &str) -> PersistentDataContainer {
+        log::warn!("RemoteBackend::load is not implemented; returning empty container for {holder_id}");
+        PersistentDataContainer::new()
+    }
+
+    fn save(&self, holder_id: &str, _container: &PersistentDataContainer) {
+        log::warn!("RemoteBackend::save is not implemented; dropping data for {holder_id}");
+    }
+}
+
+/// Tracks which keys have changed since the last flush so a backend only has to
+/// persist dirty data. A holder can keep one alongside its container to batch
+/// writes.
+#[derive(Default)]
+pub struct DirtyKeys {
+    keys: DashMap<NamespacedKey, ()>,
+}
+
+impl DirtyKeys {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks `key` as changed.
+    pub fn mark(&self, key: &NamespacedKey) {
+        self.keys.insert(key.clone(), ());
+    }
+
+    /// Returns and clears the set of dirty keys.
+    #[must_use]
+    pub fn drain(&self) -> HashSet<NamespacedKey> {
+        let drained = self.keys.iter().map(|e| e.key().clone()).collect();
+        self.keys.clear();
+        drained
+    }
+
+    /// Returns whether any keys are dirty.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        !self.keys.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugin::persistence::{NestedContainer, PersistentDataType};
+    use std::collections::HashMap;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("pumpkin-pdc-{}-{name}", std::process::id()))
+    }
+
+    fn key(key: &str) -> NamespacedKey {
+        NamespacedKey::new("test", key).unwrap()
+    }
+
+    #[test]
+    fn file_backend_survives_restart_without_corruption() {
+        let dir = temp_dir("restart");
+        let container = PersistentDataContainer::new();
+        container.insert(key("byte"), PersistentDataType::U8(5));
+        container.insert(key("short"), PersistentDataType::U16(40_000));
+        container.insert(key("wide"), PersistentDataType::U64(u64::MAX));
+        container.insert(key("glyph"), PersistentDataType::Char('ß'));
+        container.insert(
+            key("empty"),
+            PersistentDataType::Compound(HashMap::new()),
+        );
+
+        // A fresh backend instance models the server coming back up.
+        FileBackend::new(&dir).save("holder", &container);
+        let reloaded = FileBackend::new(&dir).load("holder");
+
+        assert_eq!(reloaded.get(&key("byte")).unwrap().value(), &PersistentDataType::U8(5));
+        assert_eq!(
+            reloaded.get(&key("short")).unwrap().value(),
+            &PersistentDataType::U16(40_000)
+        );
+        assert_eq!(
+            reloaded.get(&key("wide")).unwrap().value(),
+            &PersistentDataType::U64(u64::MAX)
+        );
+        assert_eq!(reloaded.get(&key("glyph")).unwrap().value(), &PersistentDataType::Char('ß'));
+        assert_eq!(
+            reloaded.get(&key("empty")).unwrap().value(),
+            &PersistentDataType::Compound(HashMap::new())
+        );
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn in_memory_backend_round_trips_nested_container() {
+        let backend = InMemoryBackend::new();
+        let nested = PersistentDataContainer::new();
+        nested.insert(key("inner"), PersistentDataType::I32(7));
+        let container = PersistentDataContainer::new();
+        container.insert(key("outer"), PersistentDataType::Container(NestedContainer(nested)));
+
+        backend.save("holder", &container);
+        let reloaded = backend.load("holder");
+        assert!(matches!(
+            reloaded.get(&key("outer")).unwrap().value(),
+            PersistentDataType::Container(_)
+        ));
+    }
+}
@@ -1,3 +1,15 @@
+/// Runs `$body` once on the next server tick, returning a [`TaskHandle`].
+///
+/// [`TaskHandle`]: $crate::plugin::api::task::TaskHandle
+#[macro_export]
+macro_rules! run_task {
+    ($server:expr, $body:block) => {{ $crate::run_task_later!($server, 0u64, $body) }};
+}
+
+/// Runs `$body` once after `$delay_ticks`, returning a [`TaskHandle`] that can
+/// cancel the pending run and report its scheduled tick.
+///
+/// [`TaskHandle`]: $crate::plugin::api::task::TaskHandle
 #[macro_export]
 macro_rules! run_task_later {
     ($server:expr, $delay_ticks:expr, $body:block) => {{
@@ -9,7 +21,7 @@ macro_rules! run_task_later {
             atomic::{AtomicBool, Ordering},
         };
         use tokio::sync::Mutex;
-        use $crate::plugin::api::task::{ScheduledHandle, TaskHandler};
+        use $crate::plugin::api::task::TaskHandler;
 
         struct InlineOnceHandler {
             cancel_flag: Arc<AtomicBool>,
@@ -42,37 +54,41 @@ macro_rules! run_task_later {
         let future: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(async move { $body });
 
         let handler = Arc::new(InlineOnceHandler {
-            cancel_flag: cancel_flag.clone(),
+            cancel_flag,
             future: Mutex::new(Some(future)),
         });
 
         $server
             .task_scheduler
-            .schedule_once($delay_ticks as u64, handler.clone())
-            .await;
-
-        ScheduledHandle {
-            handler,
-            cancel_flag,
-        }
+            .schedule_delayed($delay_ticks as u64, handler)
     }};
 }
 
+/// Runs `$body` every `$interval_ticks`, returning a [`TaskHandle`].
+///
+/// The body is a closure `|ctx| async { .. }` whose `ctx` is a
+/// [`TaskContext`], so the task can cancel itself cleanly and read its own run
+/// count from inside the handler.
+///
+/// [`TaskHandle`]: $crate::plugin::api::task::TaskHandle
+/// [`TaskContext`]: $crate::plugin::api::task::TaskContext
 #[macro_export]
 macro_rules! run_task_timer {
-    ($server:expr, $interval_ticks:expr, |$handle_ident:ident| $body:expr) => {{
+    ($server:expr, $interval_ticks:expr, |$ctx:ident| $body:expr) => {{
         use async_trait::async_trait;
         use std::future::Future;
         use std::pin::Pin;
         use std::sync::{
-            Arc,
+            Arc, Mutex as StdMutex,
             atomic::{AtomicBool, Ordering},
         };
-        use $crate::plugin::api::task::{RepeatingHandle, TaskHandler};
+        use $crate::plugin::api::task::{TaskContext, TaskHandler};
 
+        // The context has to be handed to the closure, but it only exists once
+        // the task is scheduled, so the closure reads it from a shared slot that
+        // is filled in immediately after scheduling.
+        let ctx_slot: Arc<StdMutex<Option<TaskContext>>> = Arc::new(StdMutex::new(None));
         let cancel_flag = Arc::new(AtomicBool::new(false));
-        let handle = RepeatingHandle::new(cancel_flag.clone());
-        let handle_arc = Arc::new(handle);
 
         struct TimerHandler {
             cancel_flag: Arc<AtomicBool>,
@@ -95,23 +111,161 @@ macro_rules! run_task_timer {
             }
         }
 
-        let closure_handle = handle_arc.clone();
+        let closure_slot = ctx_slot.clone();
         let closure = Arc::new(move || {
-            let $handle_ident = closure_handle.clone();
+            let $ctx: TaskContext = closure_slot
+                .lock()
+                .unwrap()
+                .clone()
+                .expect("task context is set before the first run");
             let fut = $body;
             Box::pin(fut) as Pin<Box<dyn Future<Output = ()> + Send>>
         });
 
         let handler = Arc::new(TimerHandler {
-            cancel_flag: cancel_flag.clone(),
+            cancel_flag,
             closure,
         });
 
-        $server
+        let handle = $server
+            .task_scheduler
+            .schedule_interval($interval_ticks as u64, handler);
+        *ctx_slot.lock().unwrap() = Some(handle.clone());
+        handle
+    }};
+}
+
+/// Schedules a task against a real-world time instead of a raw tick delta.
+///
+/// Two forms are supported, mirroring [`run_task_later!`] and [`run_task_timer!`]:
+///
+/// * `run_task_at!(server, target, { .. })` runs once at an absolute wall-clock
+///   `target` (anything implementing [`FireAt`], e.g. a `SystemTime` or
+///   `chrono::DateTime`). Targets in the past fire on the next tick.
+/// * `run_task_at!(server, cron = recurrence, |ctx| fut)` runs repeatedly,
+///   recomputing the delay from the [`Recurrence`] after each run (e.g.
+///   `DailyAt { hour: 4, minute: 0 }` for a nightly restart).
+///
+/// Both return a [`TaskHandle`] and reuse the same [`TaskHandler`]/cancel-flag
+/// machinery as the tick-based macros.
+///
+/// [`FireAt`]: $crate::plugin::api::task::FireAt
+/// [`Recurrence`]: $crate::plugin::api::task::Recurrence
+/// [`TaskHandle`]: $crate::plugin::api::task::TaskHandle
+#[macro_export]
+macro_rules! run_task_at {
+    ($server:expr, cron = $recurrence:expr, |$ctx:ident| $body:expr) => {{
+        use async_trait::async_trait;
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::sync::{
+            Arc, Mutex as StdMutex,
+            atomic::{AtomicBool, Ordering},
+        };
+        use $crate::plugin::api::task::{Recurrence, TaskContext, TaskHandler};
+
+        let ctx_slot: Arc<StdMutex<Option<TaskContext>>> = Arc::new(StdMutex::new(None));
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+
+        struct CronHandler {
+            cancel_flag: Arc<AtomicBool>,
+            closure: Arc<dyn Fn() -> Pin<Box<dyn Future<Output = ()> + Send>> + Send + Sync>,
+        }
+
+        #[async_trait]
+        impl TaskHandler for CronHandler {
+            async fn run(&self) {
+                if self.cancel_flag.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let fut = (self.closure)();
+                fut.await;
+            }
+
+            async fn cancel(&self) {
+                self.cancel_flag.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let closure_slot = ctx_slot.clone();
+        let closure = Arc::new(move || {
+            let $ctx: TaskContext = closure_slot
+                .lock()
+                .unwrap()
+                .clone()
+                .expect("task context is set before the first run");
+            let fut = $body;
+            Box::pin(fut) as Pin<Box<dyn Future<Output = ()> + Send>>
+        });
+
+        let handler = Arc::new(CronHandler {
+            cancel_flag,
+            closure,
+        });
+
+        let recurrence = Arc::new($recurrence);
+        let first_delay = recurrence.next_delay_ticks();
+        let next_delay: Arc<dyn Fn() -> u64 + Send + Sync> = {
+            let recurrence = recurrence.clone();
+            Arc::new(move || recurrence.next_delay_ticks())
+        };
+
+        let handle = $server
             .task_scheduler
-            .schedule_repeating($interval_ticks, handler.clone())
-            .await;
+            .schedule_cron(first_delay, next_delay, handler);
+        *ctx_slot.lock().unwrap() = Some(handle.clone());
+        handle
+    }};
+
+    ($server:expr, $target:expr, $body:block) => {{
+        use async_trait::async_trait;
+        use std::future::Future;
+        use std::pin::Pin;
+        use std::sync::{
+            Arc,
+            atomic::{AtomicBool, Ordering},
+        };
+        use tokio::sync::Mutex;
+        use $crate::plugin::api::task::{FireAt, TaskHandler};
+
+        struct InlineOnceHandler {
+            cancel_flag: Arc<AtomicBool>,
+            future: Mutex<Option<Pin<Box<dyn Future<Output = ()> + Send>>>>,
+        }
+
+        #[async_trait]
+        impl TaskHandler for InlineOnceHandler {
+            async fn run(&self) {
+                if self.cancel_flag.load(Ordering::Relaxed) {
+                    return;
+                }
+
+                let fut = {
+                    let mut guard = self.future.lock().await;
+                    guard.take()
+                };
+
+                if let Some(fut) = fut {
+                    fut.await;
+                }
+            }
+
+            async fn cancel(&self) {
+                self.cancel_flag.store(true, Ordering::Relaxed);
+            }
+        }
+
+        let cancel_flag = Arc::new(AtomicBool::new(false));
+        let future: Pin<Box<dyn Future<Output = ()> + Send>> = Box::pin(async move { $body });
+
+        let handler = Arc::new(InlineOnceHandler {
+            cancel_flag,
+            future: Mutex::new(Some(future)),
+        });
+
+        let delay_ticks = FireAt::ticks_from_now(&$target);
 
-        handle_arc
+        $server.task_scheduler.schedule_delayed(delay_ticks, handler)
     }};
 }
@@ -1,6 +1,6 @@
 pub mod macros;
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 
 #[async_trait::async_trait]
@@ -17,14 +17,166 @@ pub enum ScheduledTaskType {
         interval_ticks: u64,
         next_run_tick: u64,
     },
+    /// A repeating task whose interval is recomputed after every run, used for
+    /// wall-clock and cron-style scheduling where the gap between fires varies.
+    Cron {
+        next_run_tick: u64,
+        next_delay: Arc<dyn Fn() -> u64 + Send + Sync>,
+    },
+}
+
+/// The number of server ticks per real-world second, used to convert wall-clock
+/// targets into the tick deltas the scheduler operates on.
+pub const TICKS_PER_SECOND: u64 = 20;
+
+/// Converts a [`std::time::Duration`] into a (rounded) number of server ticks.
+#[must_use]
+pub fn ticks_from_duration(duration: std::time::Duration) -> u64 {
+    (duration.as_secs_f64() * TICKS_PER_SECOND as f64).round() as u64
+}
+
+/// A one-shot wall-clock target that can be resolved to a delay in ticks from
+/// now. Past targets clamp to zero so the task fires on the next tick.
+pub trait FireAt {
+    /// The delay, in ticks, between now and this target.
+    fn ticks_from_now(&self) -> u64;
+}
+
+impl FireAt for std::time::Duration {
+    fn ticks_from_now(&self) -> u64 {
+        ticks_from_duration(*self)
+    }
+}
+
+impl FireAt for std::time::SystemTime {
+    fn ticks_from_now(&self) -> u64 {
+        self.duration_since(std::time::SystemTime::now())
+            .map(ticks_from_duration)
+            .unwrap_or(0)
+    }
+}
+
+impl<Tz: chrono::TimeZone> FireAt for chrono::DateTime<Tz> {
+    fn ticks_from_now(&self) -> u64 {
+        (self.clone().with_timezone(&chrono::Utc) - chrono::Utc::now())
+            .to_std()
+            .map(ticks_from_duration)
+            .unwrap_or(0)
+    }
+}
+
+/// A recurring schedule that yields the delay, in ticks, until its next fire.
+/// Implementors are queried once up front and again after each run so the
+/// interval can track real-world time.
+pub trait Recurrence: Send + Sync + 'static {
+    /// Ticks from now until the next occurrence.
+    fn next_delay_ticks(&self) -> u64;
+}
+
+/// A fixed recurring interval.
+pub struct Interval(pub std::time::Duration);
+
+impl Recurrence for Interval {
+    fn next_delay_ticks(&self) -> u64 {
+        ticks_from_duration(self.0)
+    }
+}
+
+/// A cron-like "every day at HH:MM" (local time) recurrence.
+pub struct DailyAt {
+    pub hour: u32,
+    pub minute: u32,
+}
+
+impl Recurrence for DailyAt {
+    fn next_delay_ticks(&self) -> u64 {
+        use chrono::{Local, NaiveTime, TimeZone};
+
+        // Fall back to re-checking in a day rather than firing every tick when
+        // the configured time is unusable (out of range, or a wall-clock time
+        // that never occurs in the local zone).
+        const DAY_TICKS: u64 = TICKS_PER_SECOND * 60 * 60 * 24;
+
+        // Reject out-of-range fields instead of panicking inside `and_hms_opt`.
+        let Some(target) = NaiveTime::from_hms_opt(self.hour, self.minute, 0) else {
+            log::error!(
+                "DailyAt has an out-of-range time {:02}:{:02}; skipping this fire",
+                self.hour,
+                self.minute
+            );
+            return DAY_TICKS;
+        };
+
+        let now = Local::now();
+        // Search a few days forward so a spring-forward gap — where the target
+        // wall-clock time does not exist today — resolves to the next day it
+        // does. `from_local_datetime` yields `None` in such a gap and two
+        // candidates in a fall-back overlap; `earliest` picks the first valid
+        // instant in both cases.
+        for days_ahead in 0i64..3 {
+            let date = now.date_naive() + chrono::Duration::days(days_ahead);
+            if let Some(next) = Local.from_local_datetime(&date.and_time(target)).earliest() {
+                if next > now {
+                    return (next - now).to_std().map(ticks_from_duration).unwrap_or(0);
+                }
+            }
+        }
+        DAY_TICKS
+    }
 }
 
 pub struct ScheduledTask {
     pub task_type: ScheduledTaskType,
     pub handler: Arc<dyn TaskHandler>,
     pub cancel_flag: Arc<AtomicBool>,
+    /// The tick this task will next run on, updated after every fire so a
+    /// [`TaskHandle`] can report it.
+    pub next_run: Arc<AtomicU64>,
+    /// How many times this task has fired so far.
+    pub run_count: Arc<AtomicU64>,
+}
+
+/// A handle to a scheduled task returned from every scheduling call.
+///
+/// Unlike the raw cancel flag, a handle can cleanly cancel the task, report
+/// whether it has already been cancelled, and introspect the next run tick and
+/// how many times it has fired.
+#[derive(Clone)]
+pub struct TaskHandle {
+    cancel_flag: Arc<AtomicBool>,
+    next_run: Arc<AtomicU64>,
+    run_count: Arc<AtomicU64>,
 }
 
+impl TaskHandle {
+    /// Cancels the task; it will not fire again and is dropped on the next tick.
+    pub fn cancel(&self) {
+        self.cancel_flag.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the task has been cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel_flag.load(Ordering::Relaxed)
+    }
+
+    /// Returns the tick this task is next scheduled to run on.
+    #[must_use]
+    pub fn next_run_tick(&self) -> u64 {
+        self.next_run.load(Ordering::Relaxed)
+    }
+
+    /// Returns how many times the task has fired so far.
+    #[must_use]
+    pub fn run_count(&self) -> u64 {
+        self.run_count.load(Ordering::Relaxed)
+    }
+}
+
+/// The context passed to the body of a repeating task so it can cancel itself
+/// cleanly and read its own run count from inside the handler.
+pub type TaskContext = TaskHandle;
+
 pub struct TaskScheduler {
     tasks: Mutex<Vec<ScheduledTask>>,
     tick_count: std::sync::atomic::AtomicU64,
@@ -39,21 +191,62 @@ impl TaskScheduler {
         }
     }
 
-    pub fn schedule_once(
+    /// Pushes a task and returns a [`TaskHandle`] over its shared state.
+    fn push(
         &self,
-        delay_ticks: u64,
+        task_type: ScheduledTaskType,
+        next_run_tick: u64,
         handler: Arc<dyn TaskHandler>,
-    ) -> Arc<AtomicBool> {
-        let current_tick = self.tick_count.load(Ordering::Relaxed);
-        let cancel_flag = Arc::new(AtomicBool::new(false));
+    ) -> TaskHandle {
+        let handle = TaskHandle {
+            cancel_flag: Arc::new(AtomicBool::new(false)),
+            next_run: Arc::new(AtomicU64::new(next_run_tick)),
+            run_count: Arc::new(AtomicU64::new(0)),
+        };
         self.tasks.lock().unwrap().push(ScheduledTask {
-            task_type: ScheduledTaskType::Later {
-                run_at_tick: current_tick + delay_ticks,
-            },
+            task_type,
             handler,
-            cancel_flag: cancel_flag.clone(),
+            cancel_flag: handle.cancel_flag.clone(),
+            next_run: handle.next_run.clone(),
+            run_count: handle.run_count.clone(),
         });
-        cancel_flag
+        handle
+    }
+
+    /// Schedules a single delayed one-shot task, returning a [`TaskHandle`].
+    pub fn schedule_delayed(&self, delay_ticks: u64, handler: Arc<dyn TaskHandler>) -> TaskHandle {
+        let run_at_tick = self.tick_count.load(Ordering::Relaxed) + delay_ticks;
+        self.push(ScheduledTaskType::Later { run_at_tick }, run_at_tick, handler)
+    }
+
+    /// Schedules a task to run on the next tick, returning a [`TaskHandle`].
+    pub fn schedule_next_tick(&self, handler: Arc<dyn TaskHandler>) -> TaskHandle {
+        self.schedule_delayed(1, handler)
+    }
+
+    /// Schedules a repeating task, returning a [`TaskHandle`].
+    pub fn schedule_interval(
+        &self,
+        interval_ticks: u64,
+        handler: Arc<dyn TaskHandler>,
+    ) -> TaskHandle {
+        let next_run_tick = self.tick_count.load(Ordering::Relaxed) + interval_ticks;
+        self.push(
+            ScheduledTaskType::Timer {
+                interval_ticks,
+                next_run_tick,
+            },
+            next_run_tick,
+            handler,
+        )
+    }
+
+    pub fn schedule_once(
+        &self,
+        delay_ticks: u64,
+        handler: Arc<dyn TaskHandler>,
+    ) -> Arc<AtomicBool> {
+        self.schedule_delayed(delay_ticks, handler).cancel_flag
     }
 
     pub fn schedule_repeating(
@@ -61,84 +254,94 @@ impl TaskScheduler {
         interval_ticks: u64,
         handler: Arc<dyn TaskHandler>,
     ) -> Arc<AtomicBool> {
-        let current_tick = self.tick_count.load(Ordering::Relaxed);
-        let cancel_flag = Arc::new(AtomicBool::new(false));
-        self.tasks.lock().unwrap().push(ScheduledTask {
-            task_type: ScheduledTaskType::Timer {
-                interval_ticks,
-                next_run_tick: current_tick + interval_ticks,
+        self.schedule_interval(interval_ticks, handler).cancel_flag
+    }
+
+    /// Schedules a repeating task whose interval is recomputed after each run by
+    /// `next_delay`. The first fire happens `first_delay_ticks` from now.
+    pub fn schedule_cron(
+        &self,
+        first_delay_ticks: u64,
+        next_delay: Arc<dyn Fn() -> u64 + Send + Sync>,
+        handler: Arc<dyn TaskHandler>,
+    ) -> TaskHandle {
+        let next_run_tick = self.tick_count.load(Ordering::Relaxed) + first_delay_ticks;
+        self.push(
+            ScheduledTaskType::Cron {
+                next_run_tick,
+                next_delay,
             },
+            next_run_tick,
             handler,
-            cancel_flag: cancel_flag.clone(),
-        });
-        cancel_flag
+        )
     }
 
-    pub fn tick(&self) {
+    /// Advances the scheduler by one server tick.
+    ///
+    /// Due handlers are awaited inline, in the order they were scheduled, before
+    /// this returns. Running on the tick loop this way keeps a task's execution
+    /// ordering deterministic relative to game ticks — a detached `tokio::spawn`
+    /// would let a handler observe a later tick's world state. Handlers should
+    /// therefore stay short and offload long work themselves.
+    pub async fn tick(&self) {
         let current_tick = self.tick_count.fetch_add(1, Ordering::Relaxed) + 1;
 
-        let mut tasks = self.tasks.lock().unwrap();
-
-        tasks.retain_mut(|task| {
-            if task.cancel_flag.load(Ordering::Relaxed) {
-                let handler = task.handler.clone();
-                tokio::spawn(async move {
-                    handler.cancel().await;
-                });
-                return false;
-            }
+        // Decide what runs under the lock (the std `MutexGuard` must not be held
+        // across an `.await`), then release it and await the handlers in order.
+        let mut to_cancel: Vec<Arc<dyn TaskHandler>> = Vec::new();
+        let mut to_run: Vec<Arc<dyn TaskHandler>> = Vec::new();
+        {
+            let mut tasks = self.tasks.lock().unwrap();
+            tasks.retain_mut(|task| {
+                if task.cancel_flag.load(Ordering::Relaxed) {
+                    to_cancel.push(task.handler.clone());
+                    return false;
+                }
 
-            match &mut task.task_type {
-                ScheduledTaskType::Later { run_at_tick } => {
-                    if *run_at_tick <= current_tick {
-                        let handler = task.handler.clone();
-                        tokio::spawn(async move {
-                            handler.run().await;
-                        });
-                        false
-                    } else {
+                match &mut task.task_type {
+                    ScheduledTaskType::Later { run_at_tick } => {
+                        if *run_at_tick <= current_tick {
+                            task.run_count.fetch_add(1, Ordering::Relaxed);
+                            to_run.push(task.handler.clone());
+                            false
+                        } else {
+                            true
+                        }
+                    }
+                    ScheduledTaskType::Timer {
+                        interval_ticks,
+                        next_run_tick,
+                    } => {
+                        if *next_run_tick <= current_tick {
+                            *next_run_tick = current_tick + *interval_ticks;
+                            task.next_run.store(*next_run_tick, Ordering::Relaxed);
+                            task.run_count.fetch_add(1, Ordering::Relaxed);
+                            to_run.push(task.handler.clone());
+                        }
                         true
                     }
-                }
-                ScheduledTaskType::Timer {
-                    interval_ticks,
-                    next_run_tick,
-                } => {
-                    if *next_run_tick <= current_tick {
-                        *next_run_tick = current_tick + *interval_ticks;
-                        let handler = task.handler.clone();
-                        tokio::spawn(async move {
-                            handler.run().await;
-                        });
+                    ScheduledTaskType::Cron {
+                        next_run_tick,
+                        next_delay,
+                    } => {
+                        if *next_run_tick <= current_tick {
+                            *next_run_tick = current_tick + next_delay().max(1);
+                            task.next_run.store(*next_run_tick, Ordering::Relaxed);
+                            task.run_count.fetch_add(1, Ordering::Relaxed);
+                            to_run.push(task.handler.clone());
+                        }
+                        true
                     }
-                    true
                 }
-            }
-        });
-    }
-}
-
-#[derive(Clone)]
-pub struct ScheduledHandle {
-    handler: Arc<dyn TaskHandler>,
-    cancel_flag: Arc<AtomicBool>,
-}
-
-impl ScheduledHandle {
-    pub async fn cancel(&self) {
-        self.cancel_flag.store(true, Ordering::Relaxed);
-        self.handler.cancel().await;
-    }
-}
-
-#[derive(Clone)]
-pub struct RepeatingHandle {
-    cancel_flag: Arc<AtomicBool>,
-}
+            });
+        }
 
-impl RepeatingHandle {
-    pub async fn cancel(&self) {
-        self.cancel_flag.store(true, Ordering::Relaxed);
+        for handler in to_cancel {
+            handler.cancel().await;
+        }
+        for handler in to_run {
+            handler.run().await;
+        }
     }
 }
 